@@ -2,18 +2,30 @@
 
 pub const FULL_DATA_BYTE: u8 = 0x03;
 pub const COMM_ACK_BYTE: u8 = 0x04;
+pub const SCROLL_BYTE: u8 = 0x05;
+pub const STOP_SCROLL_BYTE: u8 = 0x06;
+
+/// Length of the data following `SCROLL_BYTE`
+pub const SCROLL_DATA_LEN: usize = 5;
 
 /// Two communications per signal, first is the opcode, second is the data(if exists)
 #[derive(Eq, PartialEq, Debug)]
 pub enum Signal<'a> {
     FullData(&'a [u8]),
     CommACK,
+    /// Start hardware scrolling, data is `[direction, start_page, end_page, interval, vertical_offset]`
+    /// direction: 0 right, 1 left
+    /// interval: index into 2, 3, 4, 5, 25, 64, 128, 256 frames per step
+    Scroll(&'a [u8]),
+    StopScroll,
 }
 
 #[derive(Debug)]
 pub enum Error {
     InvalidOpcode,
     MissingData,
+    /// Data is present but not the length the opcode expects
+    InvalidLength,
 }
 
 impl<'a> Signal<'a> {
@@ -23,6 +35,8 @@ impl<'a> Signal<'a> {
         match self {
             Signal::FullData(data) => (FULL_DATA_BYTE, Some(data)),
             Signal::CommACK => (COMM_ACK_BYTE, None),
+            Signal::Scroll(data) => (SCROLL_BYTE, Some(data)),
+            Signal::StopScroll => (STOP_SCROLL_BYTE, None),
         }
     }
 
@@ -30,12 +44,20 @@ impl<'a> Signal<'a> {
         Ok(match op {
             FULL_DATA_BYTE => Signal::FullData(data.ok_or(Error::MissingData)?),
             COMM_ACK_BYTE => Signal::CommACK,
+            SCROLL_BYTE => {
+                let data = data.ok_or(Error::MissingData)?;
+                if data.len() != SCROLL_DATA_LEN {
+                    return Err(Error::InvalidLength);
+                }
+                Signal::Scroll(data)
+            }
+            STOP_SCROLL_BYTE => Signal::StopScroll,
             _ => return Err(Error::InvalidOpcode),
         })
     }
 
     #[inline(always)]
     pub fn has_data(opcode: u8) -> bool {
-        opcode == FULL_DATA_BYTE
+        opcode == FULL_DATA_BYTE || opcode == SCROLL_BYTE
     }
 }
@@ -1,7 +1,7 @@
 use std::{fs, io::{self, Write}, thread::sleep, time::Duration};
 
 use bw_img::{file::compress, iter_direction, IterOutput};
-use bw_img_comm::{COMM_ACK_BYTE, FULL_DATA_BYTE};
+use bw_img_comm::{COMM_ACK_BYTE, FULL_DATA_BYTE, SCROLL_BYTE};
 use clap::Parser;
 use eyre::Context;
 
@@ -11,6 +11,15 @@ struct Args {
     input: String,
     #[clap(short, long, default_value = "/dev/ttyACM0")]
     dev_path: String,
+    /// Scroll the last frame with the display's hardware scrolling after playback
+    #[clap(short, long)]
+    scroll: Option<ScrollDirection>,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum ScrollDirection {
+    Right,
+    Left,
 }
 
 const FRAME_RATE: u32 = 30;
@@ -59,6 +68,16 @@ fn main() -> eyre::Result<()> {
     }
     println!();
 
+    if let Some(direction) = args.scroll {
+        // all 8 pages, one step every 2 frames, no vertical offset
+        let params = [direction as u8, 0, 7, 0, 0];
+        device.write_all(&[SCROLL_BYTE])?;
+        sleep(Duration::from_millis(1));
+        device.write_all(&params)?;
+        read_ack(&mut device).wrap_err_with(|| "read err")?;
+        println!("Scrolling started");
+    }
+
     Ok(())
 }
 
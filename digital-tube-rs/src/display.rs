@@ -0,0 +1,208 @@
+use crate::error::Error;
+use crate::format::OVERFLOW;
+use crate::segment::{SegDisplay, Segment, TubeDisplay};
+
+macro_rules! tube_display {
+    ($name:ident, $bits:expr) => {
+        pub struct $name;
+        impl $name {
+            const BITS: u8 = $bits;
+        }
+        impl SegDisplay for $name {
+            #[inline(always)]
+            fn bits(self) -> u8 {
+                Self::BITS
+            }
+        }
+    };
+}
+
+tube_display!(
+    Num0,
+    Segment::A | Segment::B | Segment::C | Segment::D | Segment::E | Segment::F
+);
+tube_display!(Num1, Segment::B | Segment::C);
+tube_display!(
+    Num2,
+    Segment::A | Segment::B | Segment::G | Segment::E | Segment::D
+);
+tube_display!(
+    Num3,
+    Segment::A | Segment::B | Segment::G | Segment::C | Segment::D
+);
+tube_display!(Num4, Segment::F | Segment::G | Segment::B | Segment::C);
+tube_display!(
+    Num5,
+    Segment::A | Segment::F | Segment::G | Segment::C | Segment::D
+);
+tube_display!(
+    Num6,
+    Segment::A | Segment::F | Segment::G | Segment::C | Segment::D | Segment::E
+);
+tube_display!(Num7, Segment::A | Segment::B | Segment::C);
+tube_display!(
+    Num8,
+    Segment::A | Segment::B | Segment::C | Segment::D | Segment::E | Segment::F | Segment::G
+);
+tube_display!(
+    Num9,
+    Segment::A | Segment::B | Segment::C | Segment::D | Segment::F | Segment::G
+);
+
+tube_display!(Blank, 0);
+tube_display!(Minus, Segment::G);
+tube_display!(Underscore, Segment::D);
+tube_display!(Degree, Segment::A | Segment::B | Segment::F | Segment::G);
+// 三横, 表示无法显示的内容
+tube_display!(Invalid, Segment::A | Segment::D | Segment::G);
+
+/// Segments of a hex digit, `None` above 15.
+#[inline(always)]
+pub fn digit_to_segments(digit: u8) -> Option<u8> {
+    match digit {
+        0..=9 => char_to_segments((b'0' + digit) as char),
+        10..=15 => char_to_segments((b'a' + digit - 10) as char),
+        _ => None,
+    }
+}
+
+/// Segments of `c`, `None` if it can't be told apart on 7 segments.
+///
+/// Letters without both cases use the one that reads, `'b'` and `'B'` are both `b`.
+pub fn char_to_segments(c: char) -> Option<u8> {
+    const A: u8 = Segment::A;
+    const B: u8 = Segment::B;
+    const C: u8 = Segment::C;
+    const D: u8 = Segment::D;
+    const E: u8 = Segment::E;
+    const F: u8 = Segment::F;
+    const G: u8 = Segment::G;
+
+    Some(match c {
+        '0' | 'O' => Num0.bits(),
+        '1' => Num1.bits(),
+        '2' => Num2.bits(),
+        '3' => Num3.bits(),
+        '4' => Num4.bits(),
+        '5' | 'S' | 's' => Num5.bits(),
+        '6' => Num6.bits(),
+        '7' => Num7.bits(),
+        '8' => Num8.bits(),
+        '9' => Num9.bits(),
+        'A' | 'a' => A | B | C | E | F | G,
+        'B' | 'b' => C | D | E | F | G,
+        'C' => A | D | E | F,
+        'c' => D | E | G,
+        'D' | 'd' => B | C | D | E | G,
+        'E' | 'e' => A | D | E | F | G,
+        'F' | 'f' => A | E | F | G,
+        'G' | 'g' => A | C | D | E | F,
+        'H' => B | C | E | F | G,
+        'h' => C | E | F | G,
+        'I' => E | F,
+        'i' => E,
+        'J' | 'j' => B | C | D | E,
+        'L' | 'l' => D | E | F,
+        'N' | 'n' => C | E | G,
+        'o' => C | D | E | G,
+        'P' | 'p' => A | B | E | F | G,
+        'Q' | 'q' => A | B | C | F | G,
+        'R' | 'r' => E | G,
+        'T' | 't' => D | E | F | G,
+        'U' => B | C | D | E | F,
+        'u' => C | D | E,
+        'Y' | 'y' => B | C | D | F | G,
+        ' ' => Blank.bits(),
+        '-' => Minus.bits(),
+        '_' => Underscore.bits(),
+        '=' => D | G,
+        '°' => Degree.bits(),
+        _ => return None,
+    })
+}
+
+/// Up to 4 characters, left aligned.
+///
+/// A `'.'` lights the point of the character before it, so `"12.5"` takes three digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Text {
+    bits: [u8; 4],
+}
+
+impl Text {
+    pub fn new(text: &str) -> Result<Self, Error> {
+        let mut bits = [0u8; 4];
+        parse(text, &mut bits)?;
+        Ok(Text { bits })
+    }
+}
+
+/// Writes the segments of `text` to `bits` and returns how many it took.
+pub(crate) fn parse(text: &str, bits: &mut [u8]) -> Result<usize, Error> {
+    let mut len = 0;
+    for c in text.chars() {
+        if c == '.' && len > 0 && bits[len - 1] & Segment::DP == 0 {
+            bits[len - 1] |= Segment::DP;
+            continue;
+        }
+        let seg = match c {
+            '.' => Segment::DP,
+            _ => char_to_segments(c).ok_or(Error::Unsupported(c))?,
+        };
+        *bits.get_mut(len).ok_or(Error::TooLong)? = seg;
+        len += 1;
+    }
+    Ok(len)
+}
+
+impl TubeDisplay for Text {
+    #[inline(always)]
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        self.bits
+    }
+}
+
+/// Decimal number with leading zeros, bit `i` of `dots` lights the point of digit `i`.
+///
+/// From 10000 on it shows `OVERFLOW`.
+#[derive(Debug, Clone)]
+pub struct Number {
+    pub n: u16,
+    pub dots: u8,
+}
+
+impl TubeDisplay for Number {
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        if self.n >= 10000 {
+            return OVERFLOW;
+        }
+        let mut number = self.n;
+        let mut segs = [0u8; 4];
+        (0..4).rev().for_each(|i| {
+            segs[i] = {
+                let digit: u8 = (number % 10) as u8;
+                number /= 10;
+                digit_to_segments(digit).unwrap_or(Invalid.bits()).combine(
+                    if (self.dots >> i) & 1 == 1 {
+                        Segment::DP
+                    } else {
+                        0
+                    },
+                )
+            }
+        });
+        segs
+    }
+}
+
+impl Number {
+    pub fn new(num: u16) -> Self {
+        Self { n: num, dots: 0 }
+    }
+
+    #[inline(always)]
+    pub fn set_dot(mut self, idx: usize) -> Self {
+        self.dots |= 1 << idx;
+        self
+    }
+}
@@ -0,0 +1,103 @@
+use core::convert::Infallible;
+
+use embedded_hal::digital::v2::OutputPin;
+
+use super::Driver;
+use crate::polarity::{Active, Polarity};
+
+/// Up to 8 multiplexed digits behind two chained 74HC595 shift registers.
+///
+/// Each `refresh` shifts out the segment byte and then the digit byte, MSB
+/// first, and latches them. So the first 595 selects digit `n` on Qn and the
+/// second one drives the segments, A on Q0 to DP on Q7. Like `Tube` it has to
+/// be refreshed from a timer, `N` times the refresh rate.
+pub struct Hc595<DATA, CLK, LATCH, const N: usize> {
+    data: DATA,
+    clock: CLK,
+    latch: LATCH,
+    polarity: Polarity,
+    states: [u8; N],
+    current: usize,
+}
+
+impl<DATA, CLK, LATCH, const N: usize> Hc595<DATA, CLK, LATCH, N>
+where
+    DATA: OutputPin,
+    CLK: OutputPin<Error = DATA::Error>,
+    LATCH: OutputPin<Error = DATA::Error>,
+{
+    const CHECK_DIGITS: () = assert!(N >= 1 && N <= 8, "one 74HC595 selects 1 to 8 digits");
+
+    /// Common anode wiring, turns everything off.
+    pub fn new(data: DATA, clock: CLK, latch: LATCH) -> Result<Self, DATA::Error> {
+        Self::with_polarity(data, clock, latch, Polarity::COMMON_ANODE)
+    }
+
+    /// Turns everything off.
+    pub fn with_polarity(
+        data: DATA,
+        clock: CLK,
+        latch: LATCH,
+        polarity: Polarity,
+    ) -> Result<Self, DATA::Error> {
+        let () = Self::CHECK_DIGITS;
+        let mut hc595 = Hc595 {
+            data,
+            clock,
+            latch,
+            polarity,
+            states: [0; N],
+            current: N - 1,
+        };
+        hc595.clock.set_low()?;
+        hc595.latch.set_low()?;
+        hc595.output(0, 0)?;
+        Ok(hc595)
+    }
+
+    pub fn release(self) -> (DATA, CLK, LATCH) {
+        (self.data, self.clock, self.latch)
+    }
+
+    /// Lights the next digit, call from a timer interrupt.
+    pub fn refresh(&mut self) -> Result<(), DATA::Error> {
+        self.current = (self.current + 1) % N;
+        self.output(self.states[self.current], 1 << self.current)
+    }
+
+    fn output(&mut self, segments: u8, digits: u8) -> Result<(), DATA::Error> {
+        self.shift(segments, self.polarity.segments)?;
+        self.shift(digits, self.polarity.digits)?;
+        self.latch.set_high()?;
+        self.latch.set_low()
+    }
+
+    fn shift(&mut self, bits: u8, active: Active) -> Result<(), DATA::Error> {
+        for i in (0..8).rev() {
+            active.drive(&mut self.data, (bits >> i) & 1 == 1)?;
+            self.clock.set_high()?;
+            self.clock.set_low()?;
+        }
+        Ok(())
+    }
+}
+
+impl<DATA, CLK, LATCH, const N: usize> Driver for Hc595<DATA, CLK, LATCH, N>
+where
+    DATA: OutputPin,
+    CLK: OutputPin<Error = DATA::Error>,
+    LATCH: OutputPin<Error = DATA::Error>,
+{
+    type Error = Infallible;
+
+    fn digits(&self) -> usize {
+        N
+    }
+
+    fn write(&mut self, pos: usize, bits: &[u8]) -> Result<(), Self::Error> {
+        for (state, bits) in self.states.iter_mut().skip(pos).zip(bits) {
+            *state = *bits;
+        }
+        Ok(())
+    }
+}
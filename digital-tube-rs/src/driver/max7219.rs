@@ -0,0 +1,112 @@
+use embedded_hal::blocking::spi::Write;
+use embedded_hal::digital::v2::OutputPin;
+
+use super::Driver;
+use crate::segment::Segment;
+
+/// Highest brightness, the chip has 16 intensity steps.
+pub const MAX_BRIGHTNESS: u8 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpiError<S, P> {
+    Spi(S),
+    Pin(P),
+}
+
+/// MAX7219 module with up to 8 digits on SPI, mode 0 up to 10 MHz.
+///
+/// The chip multiplexes by itself. Its digit 0 is the rightmost one on the
+/// usual modules, so digit `pos` here is register `N - pos`. Decoding is
+/// off, segment bits are reordered to the chip's DP, A..G.
+pub struct Max7219<SPI, CS, const N: usize> {
+    spi: SPI,
+    cs: CS,
+}
+
+impl<SPI, CS, const N: usize> Max7219<SPI, CS, N>
+where
+    SPI: Write<u8>,
+    CS: OutputPin,
+{
+    const DECODE_MODE: u8 = 0x09;
+    const INTENSITY: u8 = 0x0A;
+    const SCAN_LIMIT: u8 = 0x0B;
+    const SHUTDOWN: u8 = 0x0C;
+    const DISPLAY_TEST: u8 = 0x0F;
+    const CHECK_DIGITS: () = assert!(N >= 1 && N <= 8, "MAX7219 drives 1 to 8 digits");
+
+    /// Clears the display and turns it on at full brightness.
+    pub fn new(spi: SPI, cs: CS) -> Result<Self, SpiError<SPI::Error, CS::Error>> {
+        let () = Self::CHECK_DIGITS;
+        let mut max = Max7219 { spi, cs };
+        max.cs.set_high().map_err(SpiError::Pin)?;
+        max.register(Self::DISPLAY_TEST, 0)?;
+        max.register(Self::DECODE_MODE, 0)?;
+        max.register(Self::SCAN_LIMIT, N as u8 - 1)?;
+        max.register(Self::INTENSITY, MAX_BRIGHTNESS)?;
+        max.write(0, &[0; N])?;
+        max.register(Self::SHUTDOWN, 1)?;
+        Ok(max)
+    }
+
+    pub fn release(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    /// Sets the brightness from 0 to `MAX_BRIGHTNESS`, clamped.
+    pub fn set_brightness(&mut self, level: u8) -> Result<(), SpiError<SPI::Error, CS::Error>> {
+        self.register(Self::INTENSITY, level.min(MAX_BRIGHTNESS))
+    }
+
+    /// Turns the display off or back on, the digits are kept.
+    pub fn set_on(&mut self, on: bool) -> Result<(), SpiError<SPI::Error, CS::Error>> {
+        self.register(Self::SHUTDOWN, on as u8)
+    }
+
+    fn register(&mut self, addr: u8, value: u8) -> Result<(), SpiError<SPI::Error, CS::Error>> {
+        self.cs.set_low().map_err(SpiError::Pin)?;
+        let res = self.spi.write(&[addr, value]).map_err(SpiError::Spi);
+        self.cs.set_high().map_err(SpiError::Pin)?;
+        res
+    }
+}
+
+impl<SPI, CS, const N: usize> Driver for Max7219<SPI, CS, N>
+where
+    SPI: Write<u8>,
+    CS: OutputPin,
+{
+    type Error = SpiError<SPI::Error, CS::Error>;
+
+    fn digits(&self) -> usize {
+        N
+    }
+
+    fn write(&mut self, pos: usize, bits: &[u8]) -> Result<(), Self::Error> {
+        for (n, bits) in (pos..N).zip(bits) {
+            self.register((N - n) as u8, to_chip(*bits))?;
+        }
+        Ok(())
+    }
+}
+
+/// `Segment` bits to the chip's no-decode order: DP, A, B, C, D, E, F, G from D7 down.
+fn to_chip(bits: u8) -> u8 {
+    const ORDER: [u8; 8] = [
+        Segment::G,
+        Segment::F,
+        Segment::E,
+        Segment::D,
+        Segment::C,
+        Segment::B,
+        Segment::A,
+        Segment::DP,
+    ];
+    ORDER
+        .iter()
+        .enumerate()
+        .fold(0, |chip, (i, seg)| match bits & seg {
+            0 => chip,
+            _ => chip | 1 << i,
+        })
+}
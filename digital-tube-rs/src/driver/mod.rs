@@ -0,0 +1,54 @@
+//! Modules that show digits, the GPIO `Tube` and driver chips alike.
+
+use core::convert::Infallible;
+
+use embedded_hal::digital::v2::OutputPin;
+
+use crate::segment::{SegDisplay, TubeDisplay};
+use crate::tube::{SegmentPort, Tube};
+
+pub mod hc595;
+pub mod max7219;
+pub mod tm1637;
+
+pub use hc595::Hc595;
+pub use max7219::Max7219;
+pub use tm1637::Tm1637;
+
+/// A 7-segment module, digits counted from the left.
+///
+/// Digits are segment bits like `Segment`, each driver maps them to its wiring.
+pub trait Driver {
+    type Error;
+
+    /// Number of digits on the module.
+    fn digits(&self) -> usize;
+
+    /// Shows `bits` from digit `pos` on, what doesn't fit is dropped.
+    fn write(&mut self, pos: usize, bits: &[u8]) -> Result<(), Self::Error>;
+
+    /// Shows `tube` on the 4 digits from `pos` on, `0` and `4` on an 8 digit module.
+    fn write_tube(&mut self, pos: usize, tube: impl TubeDisplay) -> Result<(), Self::Error> {
+        self.write(pos, &tube.tube_bits().map(|s| s.bits()))
+    }
+}
+
+/// Writes go to the multiplexing state, `refresh` puts them on the pins.
+impl<SEG, DIG> Driver for Tube<SEG, DIG>
+where
+    SEG: SegmentPort,
+    DIG: OutputPin<Error = SEG::Error>,
+{
+    type Error = Infallible;
+
+    fn digits(&self) -> usize {
+        4
+    }
+
+    fn write(&mut self, pos: usize, bits: &[u8]) -> Result<(), Self::Error> {
+        for (n, bits) in (pos..4).zip(bits) {
+            self.set_display(n, *bits);
+        }
+        Ok(())
+    }
+}
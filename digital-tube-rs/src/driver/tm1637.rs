@@ -0,0 +1,156 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+
+use super::Driver;
+
+/// Highest brightness, the chip has 8 pulse widths.
+pub const MAX_BRIGHTNESS: u8 = 7;
+
+/// Microseconds per half clock, the chip takes up to 250 kHz.
+const HALF_CLOCK: u32 = 5;
+
+/// TM1637 module with up to 6 digits on its CLK/DIO 2-wire bus.
+///
+/// Both lines should be open drain with pull-ups, the acknowledge bit is
+/// clocked through but not read. The chip multiplexes by itself, every write
+/// goes to its display RAM straight away.
+pub struct Tm1637<CLK, DIO, D, const N: usize> {
+    clock: CLK,
+    data: DIO,
+    delay: D,
+    brightness: u8,
+    on: bool,
+}
+
+impl<CLK, DIO, D, const N: usize> Tm1637<CLK, DIO, D, N>
+where
+    CLK: OutputPin,
+    DIO: OutputPin<Error = CLK::Error>,
+    D: DelayUs<u32>,
+{
+    const DATA_AUTO: u8 = 0x40;
+    const ADDRESS: u8 = 0xC0;
+    const DISPLAY_OFF: u8 = 0x80;
+    const DISPLAY_ON: u8 = 0x88;
+    const CHECK_DIGITS: () = assert!(N >= 1 && N <= 6, "TM1637 drives 1 to 6 digits");
+
+    /// Clears the display and turns it on at full brightness.
+    pub fn new(clock: CLK, data: DIO, delay: D) -> Result<Self, CLK::Error> {
+        let () = Self::CHECK_DIGITS;
+        let mut tm = Tm1637 {
+            clock,
+            data,
+            delay,
+            brightness: MAX_BRIGHTNESS,
+            on: true,
+        };
+        tm.clock.set_high()?;
+        tm.data.set_high()?;
+        tm.write_ram(0, &[0; N])?;
+        tm.update_control()?;
+        Ok(tm)
+    }
+
+    pub fn release(self) -> (CLK, DIO, D) {
+        (self.clock, self.data, self.delay)
+    }
+
+    /// Sets the brightness from 0 to `MAX_BRIGHTNESS`, clamped.
+    pub fn set_brightness(&mut self, level: u8) -> Result<(), CLK::Error> {
+        self.brightness = level.min(MAX_BRIGHTNESS);
+        self.update_control()
+    }
+
+    /// Turns the display off or back on, the RAM is kept.
+    pub fn set_on(&mut self, on: bool) -> Result<(), CLK::Error> {
+        self.on = on;
+        self.update_control()
+    }
+
+    fn update_control(&mut self) -> Result<(), CLK::Error> {
+        let cmd = if self.on {
+            Self::DISPLAY_ON | self.brightness
+        } else {
+            Self::DISPLAY_OFF
+        };
+        self.command(&[cmd])
+    }
+
+    fn write_ram(&mut self, pos: usize, bits: &[u8]) -> Result<(), CLK::Error> {
+        self.command(&[Self::DATA_AUTO])?;
+        self.start()?;
+        self.byte(Self::ADDRESS | pos as u8)?;
+        bits.iter().try_for_each(|b| self.byte(*b))?;
+        self.stop()
+    }
+
+    fn command(&mut self, bytes: &[u8]) -> Result<(), CLK::Error> {
+        self.start()?;
+        bytes.iter().try_for_each(|b| self.byte(*b))?;
+        self.stop()
+    }
+
+    fn start(&mut self) -> Result<(), CLK::Error> {
+        self.data.set_low()?;
+        self.wait();
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), CLK::Error> {
+        self.clock.set_low()?;
+        self.data.set_low()?;
+        self.wait();
+        self.clock.set_high()?;
+        self.wait();
+        self.data.set_high()?;
+        self.wait();
+        Ok(())
+    }
+
+    /// LSB first, then a clock for the acknowledge with DIO released.
+    fn byte(&mut self, byte: u8) -> Result<(), CLK::Error> {
+        for i in 0..8 {
+            self.clock.set_low()?;
+            if (byte >> i) & 1 == 1 {
+                self.data.set_high()?;
+            } else {
+                self.data.set_low()?;
+            }
+            self.wait();
+            self.clock.set_high()?;
+            self.wait();
+        }
+        self.clock.set_low()?;
+        self.data.set_high()?;
+        self.wait();
+        self.clock.set_high()?;
+        self.wait();
+        Ok(())
+    }
+
+    fn wait(&mut self) {
+        self.delay.delay_us(HALF_CLOCK);
+    }
+}
+
+/// Segment bits are the same as the chip's, bit 7 is the colon on clock modules.
+impl<CLK, DIO, D, const N: usize> Driver for Tm1637<CLK, DIO, D, N>
+where
+    CLK: OutputPin,
+    DIO: OutputPin<Error = CLK::Error>,
+    D: DelayUs<u32>,
+{
+    type Error = CLK::Error;
+
+    fn digits(&self) -> usize {
+        N
+    }
+
+    fn write(&mut self, pos: usize, bits: &[u8]) -> Result<(), Self::Error> {
+        if pos >= N {
+            return Ok(());
+        }
+        let len = bits.len().min(N - pos);
+        self.write_ram(pos, &bits[..len])
+    }
+}
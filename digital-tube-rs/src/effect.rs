@@ -0,0 +1,75 @@
+//! Animations run by `Tube::refresh`, their speeds are counted in scans: one
+//! round of all 4 digits, `4 * BRIGHTNESS_LEVELS` refresh calls.
+
+use crate::display::parse;
+use crate::error::Error;
+use crate::segment::{SegDisplay, TubeDisplay};
+
+/// Most characters a `Marquee` holds.
+pub const MARQUEE_LEN: usize = 32;
+
+/// Blank digits between the end of a marquee and its next start.
+const GAP: usize = 4;
+
+/// Text scrolling right to left, one digit per step.
+///
+/// Up to 4 characters it stands still like `Text`. Longer ones start with the
+/// first 4 shown and come round again after a blank gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marquee {
+    bits: [u8; MARQUEE_LEN],
+    len: usize,
+    pos: usize,
+}
+
+impl Marquee {
+    pub fn new(text: &str) -> Result<Self, Error> {
+        let mut bits = [0u8; MARQUEE_LEN];
+        let len = parse(text, &mut bits)?;
+        Ok(Marquee { bits, len, pos: 0 })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn scrolls(&self) -> bool {
+        self.len > 4
+    }
+
+    /// Steps until the text is back where it started.
+    pub fn period(&self) -> usize {
+        if self.scrolls() {
+            self.len + GAP
+        } else {
+            1
+        }
+    }
+
+    /// Moves the text one digit to the left.
+    pub fn step(&mut self) {
+        self.pos = (self.pos + 1) % self.period();
+    }
+
+    /// The 4 digits shown at the current step.
+    pub fn frame(&self) -> [u8; 4] {
+        core::array::from_fn(|i| {
+            let at = (self.pos + i) % self.period().max(4);
+            if at < self.len {
+                self.bits[at]
+            } else {
+                0
+            }
+        })
+    }
+}
+
+impl TubeDisplay for Marquee {
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        self.frame()
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The character has no 7-segment glyph.
+    Unsupported(char),
+    /// More characters than digits.
+    TooLong,
+}
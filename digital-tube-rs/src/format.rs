@@ -0,0 +1,117 @@
+//! Numbers right aligned on the tube, with a leading minus and automatic decimal point.
+
+use crate::display::{digit_to_segments, Minus};
+use crate::segment::{SegDisplay, Segment, TubeDisplay};
+
+/// Shown instead of a value that doesn't fit in 4 digits.
+pub const OVERFLOW: [u8; 4] = [Segment::G; 4];
+
+/// Integer from -999 to 9999, blank padded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signed {
+    pub n: i32,
+}
+
+impl Signed {
+    pub fn new(n: i32) -> Self {
+        Signed { n }
+    }
+}
+
+impl TubeDisplay for Signed {
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        render(self.n as i64, 0).unwrap_or(OVERFLOW)
+    }
+}
+
+/// `value / 10^decimals`, fractional digits are rounded off until it fits.
+///
+/// `Fixed::new(2345, 2)` shows `23.45`, `Fixed::new(-2345, 2)` shows `-23.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixed {
+    pub value: i32,
+    pub decimals: u8,
+}
+
+impl Fixed {
+    pub fn new(value: i32, decimals: u8) -> Self {
+        Fixed { value, decimals }
+    }
+}
+
+impl TubeDisplay for Fixed {
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        let mut value = self.value as i64;
+        let mut decimals = self.decimals;
+        loop {
+            if let Some(bits) = render(value, decimals) {
+                return bits;
+            }
+            if decimals == 0 {
+                return OVERFLOW;
+            }
+            value = div_round(value, 10);
+            decimals -= 1;
+        }
+    }
+}
+
+/// Float with as many decimals as fit, `NaN` and infinities show as overflow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Float {
+    pub value: f32,
+}
+
+impl Float {
+    pub fn new(value: f32) -> Self {
+        Float { value }
+    }
+}
+
+impl TubeDisplay for Float {
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        // beyond 4 digits it's overflow anyway, keep the scaled value well in range
+        if !(self.value > -1e4 && self.value < 1e4) {
+            return OVERFLOW;
+        }
+        let half = if self.value < 0.0 { -0.5 } else { 0.5 };
+        // round once per try, rounding the rounded value again can be off by one
+        let mut scale = 1000.0;
+        for decimals in (0..=3).rev() {
+            if let Some(bits) = render((self.value * scale + half) as i64, decimals) {
+                return bits;
+            }
+            scale /= 10.0;
+        }
+        OVERFLOW
+    }
+}
+
+fn div_round(value: i64, by: i64) -> i64 {
+    let half = if value < 0 { -by / 2 } else { by / 2 };
+    (value + half) / by
+}
+
+/// Right aligned digits of `value` with the point before the last `decimals`,
+/// `None` if it takes more than 4 digits.
+fn render(value: i64, decimals: u8) -> Option<[u8; 4]> {
+    let mut bits = [0u8; 4];
+    let mut rest = value.unsigned_abs();
+    let mut i: usize = 4;
+    // at least one digit in front of the point
+    let mut left = decimals as usize + 1;
+    while rest > 0 || left > 0 {
+        i = i.checked_sub(1)?;
+        bits[i] = digit_to_segments((rest % 10) as u8)?;
+        rest /= 10;
+        left = left.saturating_sub(1);
+    }
+    if decimals > 0 {
+        bits[3 - decimals as usize] |= Segment::DP;
+    }
+    if value < 0 {
+        i = i.checked_sub(1)?;
+        bits[i] = Minus.bits();
+    }
+    Some(bits)
+}
@@ -0,0 +1,19 @@
+#![no_std]
+
+pub mod display;
+pub mod driver;
+pub mod effect;
+mod error;
+pub mod format;
+pub mod polarity;
+pub mod segment;
+pub mod tube;
+
+pub use display::{char_to_segments, digit_to_segments, Number, Text};
+pub use driver::{Driver, Hc595, Max7219, Tm1637};
+pub use effect::{Marquee, MARQUEE_LEN};
+pub use error::Error;
+pub use format::{Fixed, Float, Signed, OVERFLOW};
+pub use polarity::{Active, Polarity};
+pub use segment::{SegDisplay, Segment, TubeDisplay};
+pub use tube::{SegmentPort, Tube, BLINK_SCANS, BRIGHTNESS_LEVELS, SCROLL_SCANS};
@@ -0,0 +1,49 @@
+use embedded_hal::digital::v2::OutputPin;
+
+/// Pin level that lights a segment or turns a digit on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Active {
+    Low,
+    High,
+}
+
+impl Active {
+    /// Drives `pin` to the active level if `on`, to the other one otherwise.
+    pub(crate) fn drive<P: OutputPin>(self, pin: &mut P, on: bool) -> Result<(), P::Error> {
+        if on == (self == Active::High) {
+            pin.set_high()
+        } else {
+            pin.set_low()
+        }
+    }
+}
+
+/// How the tube is wired, the active level of the segment and digit pins.
+///
+/// The consts are for a tube driven straight from the pins, a transistor on
+/// the digit lines usually inverts `digits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Polarity {
+    pub segments: Active,
+    pub digits: Active,
+}
+
+impl Polarity {
+    /// Segments are cathodes, lit low, digits are anodes, on high.
+    pub const COMMON_ANODE: Self = Polarity {
+        segments: Active::Low,
+        digits: Active::High,
+    };
+
+    /// Segments are anodes, lit high, digits are cathodes, on low.
+    pub const COMMON_CATHODE: Self = Polarity {
+        segments: Active::High,
+        digits: Active::Low,
+    };
+}
+
+impl Default for Polarity {
+    fn default() -> Self {
+        Self::COMMON_ANODE
+    }
+}
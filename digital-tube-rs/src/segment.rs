@@ -0,0 +1,53 @@
+/// Segment bits, one per pin of a 7-segment digit.
+///
+/// ```text
+///  -A-
+/// F   B
+///  -G-
+/// E   C
+///  -D-  DP
+/// ```
+pub struct Segment;
+
+impl Segment {
+    pub const A: u8 = 1 << 0;
+    pub const B: u8 = 1 << 1;
+    pub const C: u8 = 1 << 2;
+    pub const D: u8 = 1 << 3;
+    pub const E: u8 = 1 << 4;
+    pub const F: u8 = 1 << 5;
+    pub const G: u8 = 1 << 6;
+    pub const DP: u8 = 1 << 7;
+}
+
+/// What one digit shows, as `Segment` bits.
+pub trait SegDisplay {
+    fn bits(self) -> u8;
+
+    #[inline(always)]
+    fn combine(self, other: impl SegDisplay) -> u8
+    where
+        Self: Sized,
+    {
+        self.bits() | other.bits()
+    }
+}
+
+impl SegDisplay for u8 {
+    #[inline(always)]
+    fn bits(self) -> u8 {
+        self
+    }
+}
+
+/// What all four digits show, leftmost first.
+pub trait TubeDisplay {
+    fn tube_bits(self) -> [impl SegDisplay; 4];
+}
+
+impl<T: SegDisplay> TubeDisplay for [T; 4] {
+    #[inline(always)]
+    fn tube_bits(self) -> [impl SegDisplay; 4] {
+        self
+    }
+}
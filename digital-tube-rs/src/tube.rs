@@ -0,0 +1,257 @@
+use embedded_hal::digital::v2::OutputPin;
+
+use crate::effect::Marquee;
+use crate::polarity::{Active, Polarity};
+use crate::segment::{SegDisplay, TubeDisplay};
+
+/// Brightness steps, each multiplex slot is this many `refresh` calls.
+pub const BRIGHTNESS_LEVELS: u8 = 8;
+
+/// Default scans per marquee step, about 3 characters a second at 90 Hz.
+pub const SCROLL_SCANS: u16 = 30;
+
+/// Default scans per blink phase, on and off once a second at 90 Hz.
+pub const BLINK_SCANS: u16 = 45;
+
+/// The 8 segment lines A, B, C, D, E, F, G and DP, shared by all digits.
+///
+/// Implemented for `[OutputPin; 8]`, which sets the pins one after the other. A
+/// board with the segments on one GPIO port can set all of them in a single
+/// register write instead.
+pub trait SegmentPort {
+    type Error;
+
+    /// Drives the lines high where `levels` has a 1, bit 0 is A.
+    fn write_levels(&mut self, levels: u8) -> Result<(), Self::Error>;
+}
+
+impl<P: OutputPin> SegmentPort for [P; 8] {
+    type Error = P::Error;
+
+    fn write_levels(&mut self, levels: u8) -> Result<(), Self::Error> {
+        self.iter_mut()
+            .enumerate()
+            .try_for_each(|(i, pin)| Active::High.drive(pin, (levels >> i) & 1 == 1))
+    }
+}
+
+/// 4 digit multiplexed 7-segment tube, like the 5461BS-1.
+///
+/// `segments` are the segment lines shared by all digits, see `SegmentPort`.
+/// `digits` select one digit each, leftmost first. Which level lights them is
+/// set by `Polarity`, `new` takes a common anode tube: segments low, digits high.
+///
+/// What is shown is set with `set_tube` or `set_display` and kept until changed,
+/// `refresh` does the multiplexing. Brightness is the share of its slot a digit
+/// stays lit, from 0 (off) to `BRIGHTNESS_LEVELS` (whole slot).
+///
+/// `scroll` and `set_blink` animate it from `refresh` as well, with speeds in
+/// scans of all 4 digits.
+pub struct Tube<SEG, DIG> {
+    segments: SEG,
+    digits: [DIG; 4],
+    polarity: Polarity,
+    states: [u8; 4],
+    brightness: [u8; 4],
+    current: usize,
+    tick: u8,
+    lit: bool,
+    marquee: Option<Marquee>,
+    scroll: Period,
+    blink: u8,
+    blink_period: Period,
+    blink_off: bool,
+}
+
+impl<SEG, DIG> Tube<SEG, DIG>
+where
+    SEG: SegmentPort,
+    DIG: OutputPin<Error = SEG::Error>,
+{
+    /// Turns everything off.
+    pub fn new(segments: SEG, digits: [DIG; 4]) -> Result<Self, SEG::Error> {
+        Self::with_polarity(segments, digits, Polarity::COMMON_ANODE)
+    }
+
+    /// Turns everything off.
+    pub fn with_polarity(
+        segments: SEG,
+        digits: [DIG; 4],
+        polarity: Polarity,
+    ) -> Result<Self, SEG::Error> {
+        let mut tube = Tube {
+            segments,
+            digits,
+            polarity,
+            states: [0; 4],
+            brightness: [BRIGHTNESS_LEVELS; 4],
+            current: 3,
+            tick: 0,
+            lit: false,
+            marquee: None,
+            scroll: Period::new(SCROLL_SCANS),
+            blink: 0,
+            blink_period: Period::new(BLINK_SCANS),
+            blink_off: false,
+        };
+        tube.digits
+            .iter_mut()
+            .try_for_each(|d| polarity.digits.drive(d, false))?;
+        tube.write_segments(0)?;
+        Ok(tube)
+    }
+
+    pub fn release(self) -> (SEG, [DIG; 4]) {
+        (self.segments, self.digits)
+    }
+
+    /// Sets what digit `n` shows, stopping the marquee.
+    pub fn set_display(&mut self, n: usize, dis: impl SegDisplay) {
+        self.marquee = None;
+        self.states[n] = dis.bits();
+    }
+
+    /// Sets what the tube shows, stopping the marquee.
+    pub fn set_tube(&mut self, tube: impl TubeDisplay) {
+        self.marquee = None;
+        self.show(tube);
+    }
+
+    /// Shows `marquee` and moves it on every `scroll_speed` scans.
+    pub fn scroll(&mut self, marquee: Marquee) {
+        self.show(marquee);
+        self.scroll.restart();
+        self.marquee = Some(marquee).filter(Marquee::scrolls);
+    }
+
+    pub fn is_scrolling(&self) -> bool {
+        self.marquee.is_some()
+    }
+
+    /// Scans per marquee step, at least 1.
+    pub fn set_scroll_speed(&mut self, scans: u16) {
+        self.scroll = Period::new(scans);
+    }
+
+    /// Blinks the digits whose bit is set in `mask`, bit 0 is the leftmost.
+    ///
+    /// A new mask starts with the digits shown.
+    pub fn set_blink(&mut self, mask: u8) {
+        if mask != self.blink {
+            self.blink = mask;
+            self.blink_off = false;
+            self.blink_period.restart();
+        }
+    }
+
+    /// Scans a blinking digit stays on and then off, at least 1.
+    pub fn set_blink_speed(&mut self, scans: u16) {
+        self.blink_period = Period::new(scans);
+    }
+
+    /// Sets the brightness of every digit, clamped to `BRIGHTNESS_LEVELS`.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = [level.min(BRIGHTNESS_LEVELS); 4];
+    }
+
+    /// Sets the brightness of digit `n`, clamped to `BRIGHTNESS_LEVELS`.
+    pub fn set_digit_brightness(&mut self, n: usize, level: u8) {
+        self.brightness[n] = level.min(BRIGHTNESS_LEVELS);
+    }
+
+    pub fn brightness(&self, n: usize) -> u8 {
+        self.brightness[n]
+    }
+
+    /// Advances the multiplexing by one step, call from a timer interrupt.
+    ///
+    /// Each digit gets a slot of `BRIGHTNESS_LEVELS` calls in turn, so the
+    /// timer runs at `4 * BRIGHTNESS_LEVELS` times the refresh rate: 2880 Hz
+    /// for a flicker free 90 Hz.
+    pub fn refresh(&mut self) -> Result<(), SEG::Error> {
+        if self.tick == 0 {
+            self.set_lit(false)?;
+            self.current = (self.current + 1) % self.digits.len();
+            if self.current == 0 {
+                self.animate();
+            }
+            let bits = if self.blink_off && (self.blink >> self.current) & 1 == 1 {
+                0
+            } else {
+                self.states[self.current]
+            };
+            self.write_segments(bits)?;
+            self.set_lit(self.brightness[self.current] > 0)?;
+        } else if self.tick >= self.brightness[self.current] {
+            self.set_lit(false)?;
+        }
+        self.tick = (self.tick + 1) % BRIGHTNESS_LEVELS;
+        Ok(())
+    }
+
+    /// Moves the effects on by one scan.
+    fn animate(&mut self) {
+        if let Some(marquee) = self.marquee.as_mut() {
+            if self.scroll.tick() {
+                marquee.step();
+                self.states = marquee.frame();
+            }
+        }
+        if self.blink != 0 && self.blink_period.tick() {
+            self.blink_off = !self.blink_off;
+        }
+    }
+
+    fn show(&mut self, tube: impl TubeDisplay) {
+        for (state, seg) in self.states.iter_mut().zip(tube.tube_bits()) {
+            *state = seg.bits();
+        }
+    }
+
+    fn set_lit(&mut self, lit: bool) -> Result<(), SEG::Error> {
+        if self.lit != lit {
+            self.polarity
+                .digits
+                .drive(&mut self.digits[self.current], lit)?;
+            self.lit = lit;
+        }
+        Ok(())
+    }
+
+    fn write_segments(&mut self, bits: u8) -> Result<(), SEG::Error> {
+        let levels = match self.polarity.segments {
+            Active::High => bits,
+            Active::Low => !bits,
+        };
+        self.segments.write_levels(levels)
+    }
+}
+
+/// Counts scans, firing every `len` of them.
+struct Period {
+    len: u16,
+    count: u16,
+}
+
+impl Period {
+    fn new(len: u16) -> Self {
+        Period {
+            len: len.max(1),
+            count: 0,
+        }
+    }
+
+    fn restart(&mut self) {
+        self.count = 0;
+    }
+
+    fn tick(&mut self) -> bool {
+        self.count += 1;
+        if self.count >= self.len {
+            self.count = 0;
+            true
+        } else {
+            false
+        }
+    }
+}
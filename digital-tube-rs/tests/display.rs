@@ -0,0 +1,83 @@
+use digital_tube_rs::{
+    char_to_segments, digit_to_segments, Error, Number, SegDisplay, Segment, Text, TubeDisplay,
+    OVERFLOW,
+};
+
+fn bits(tube: impl TubeDisplay) -> [u8; 4] {
+    tube.tube_bits().map(|s| s.bits())
+}
+
+#[test]
+fn digits_light_expected_segments() {
+    assert_eq!(digit_to_segments(1), Some(Segment::B | Segment::C));
+    assert_eq!(digit_to_segments(8), Some(0x7F));
+    assert_eq!(
+        digit_to_segments(7),
+        Some(Segment::A | Segment::B | Segment::C)
+    );
+}
+
+#[test]
+fn number_pads_with_leading_zeros() {
+    let [zero, four, two] = [0, 4, 2].map(|d| digit_to_segments(d).unwrap());
+    assert_eq!(bits(Number::new(42)), [zero, zero, four, two]);
+}
+
+#[test]
+fn set_dot_adds_decimal_point() {
+    let tube = bits(Number::new(1234).set_dot(1));
+    assert_eq!(tube[1], digit_to_segments(2).unwrap() | Segment::DP);
+    assert_eq!(tube[0] & Segment::DP, 0);
+}
+
+#[test]
+fn number_shows_overflow_for_five_digits() {
+    assert_eq!(bits(Number::new(10000)), OVERFLOW);
+}
+
+#[test]
+fn arrays_display_as_is() {
+    assert_eq!(bits([1u8, 2, 3, 4]), [1, 2, 3, 4]);
+}
+
+#[test]
+fn hex_digits_and_out_of_range() {
+    assert_eq!(digit_to_segments(0xA), char_to_segments('A'));
+    assert_eq!(digit_to_segments(0xB), Some(0x7C));
+    assert_eq!(digit_to_segments(0xF), char_to_segments('F'));
+    assert_eq!(digit_to_segments(16), None);
+}
+
+#[test]
+fn text_maps_characters() {
+    let text = Text::new("-Hi°").unwrap();
+    assert_eq!(bits(text), [Segment::G, 0x76, Segment::E, 0x63]);
+}
+
+#[test]
+fn text_merges_dot_into_previous_character() {
+    let text = Text::new("12.5").unwrap();
+    assert_eq!(
+        bits(text),
+        [
+            char_to_segments('1').unwrap(),
+            char_to_segments('2').unwrap() | Segment::DP,
+            char_to_segments('5').unwrap(),
+            0,
+        ]
+    );
+    assert_eq!(
+        bits(Text::new("..").unwrap()),
+        [Segment::DP, Segment::DP, 0, 0]
+    );
+}
+
+#[test]
+fn text_reports_unrepresentable_input() {
+    assert_eq!(Text::new("mw"), Err(Error::Unsupported('m')));
+    assert_eq!(Text::new("ABCDE"), Err(Error::TooLong));
+    assert_eq!(
+        Text::new("AbCd.").map(bits),
+        Ok([0x77, 0x7C, 0x39, 0x5E | 0x80])
+    );
+}
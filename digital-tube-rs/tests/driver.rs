@@ -0,0 +1,188 @@
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use digital_tube_rs::driver::max7219::MAX_BRIGHTNESS;
+use digital_tube_rs::{
+    Driver, Hc595, Max7219, SegDisplay, Segment, Text, Tm1637, Tube, TubeDisplay,
+};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::eh0::delay::NoopDelay;
+use embedded_hal_mock::eh0::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+use embedded_hal_mock::eh0::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+type Log = Rc<RefCell<Vec<(usize, bool)>>>;
+
+/// Pin recording its level changes to a log shared with the other wires.
+#[derive(Clone)]
+struct Wire {
+    id: usize,
+    log: Log,
+}
+
+impl OutputPin for Wire {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.id, false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.id, true));
+        Ok(())
+    }
+}
+
+fn wires<const N: usize>() -> ([Wire; N], Log) {
+    let log = Log::default();
+    (
+        core::array::from_fn(|id| Wire {
+            id,
+            log: log.clone(),
+        }),
+        log,
+    )
+}
+
+/// Latched outputs of two chained 595s, data on wire 0, clock 1, latch 2.
+fn hc595_outputs(log: &Log) -> Vec<u16> {
+    let mut levels = [false; 3];
+    let (mut shift, mut outputs) = (0u16, Vec::new());
+    for (id, level) in log.borrow_mut().drain(..) {
+        let rising = level && !levels[id];
+        levels[id] = level;
+        match id {
+            1 if rising => shift = shift << 1 | levels[0] as u16,
+            2 if rising => outputs.push(shift),
+            _ => {}
+        }
+    }
+    outputs
+}
+
+/// Bytes of each TM1637 frame, clock on wire 0, data on wire 1, both idle high.
+fn tm1637_frames(log: &Log) -> Vec<Vec<u8>> {
+    let (mut clock, mut data) = (true, true);
+    let (mut frames, mut bits) = (Vec::new(), Vec::new());
+    for (id, level) in log.borrow_mut().drain(..) {
+        match id {
+            0 => {
+                if level && !clock {
+                    bits.push(data);
+                }
+                clock = level;
+            }
+            _ => {
+                if clock && data && !level {
+                    bits.clear();
+                } else if clock && !data && level {
+                    // 8 data bits and the acknowledge for each byte, stop clocks one more
+                    let bytes = bits
+                        .chunks_exact(9)
+                        .map(|byte| (0..8).fold(0u8, |b, i| b | (byte[i] as u8) << i));
+                    frames.push(bytes.collect());
+                }
+                data = level;
+            }
+        }
+    }
+    frames
+}
+
+#[test]
+fn hc595_shifts_segments_then_digit() {
+    let ([data, clock, latch], log) = wires();
+    let mut hc595 = Hc595::<_, _, _, 8>::new(data, clock, latch).unwrap();
+    // common anode: segments inverted, no digit selected
+    assert_eq!(hc595_outputs(&log), [0xFF00]);
+
+    hc595.write_tube(4, [Segment::A, Segment::B, 0, 0]).unwrap();
+    (0..6).for_each(|_| hc595.refresh().unwrap());
+    let outputs = hc595_outputs(&log);
+    assert_eq!(outputs[0], 0xFF01);
+    assert_eq!(outputs[4], (!Segment::A as u16) << 8 | 1 << 4);
+    assert_eq!(outputs[5], (!Segment::B as u16) << 8 | 1 << 5);
+}
+
+#[test]
+fn tm1637_writes_ram_and_brightness() {
+    let ([clock, data], log) = wires();
+    let mut tm = Tm1637::<_, _, _, 4>::new(clock, data, NoopDelay).unwrap();
+    assert_eq!(
+        tm1637_frames(&log),
+        [vec![0x40], vec![0xC0, 0, 0, 0, 0], vec![0x8F]]
+    );
+
+    tm.write_tube(0, Text::new("12.34").unwrap()).unwrap();
+    tm.write(3, &[Segment::G, Segment::G]).unwrap();
+    tm.set_brightness(2).unwrap();
+    tm.set_on(false).unwrap();
+    let mut ram = vec![0xC0];
+    ram.extend(Text::new("12.34").unwrap().tube_bits().map(|s| s.bits()));
+    assert_eq!(
+        tm1637_frames(&log),
+        [
+            vec![0x40],
+            ram,
+            vec![0x40],
+            vec![0xC3, Segment::G],
+            vec![0x8A],
+            vec![0x80],
+        ]
+    );
+}
+
+#[test]
+fn max7219_maps_digits_from_the_right() {
+    let reg = |addr, value| SpiTransaction::write(vec![addr, value]);
+    let mut expected: Vec<_> = [(0x0F, 0), (0x09, 0), (0x0B, 7), (0x0A, MAX_BRIGHTNESS)]
+        .into_iter()
+        .chain((1..=8).rev().map(|addr| (addr, 0)))
+        .chain([(0x0C, 1)])
+        .map(|(addr, value)| reg(addr, value))
+        .collect();
+    // digit 0 is register 8, A is bit 6 and DP bit 7
+    expected.extend([
+        reg(8, 0b0100_0000),
+        reg(7, 0b1000_0001),
+        reg(0x0A, MAX_BRIGHTNESS),
+    ]);
+    let mut spi = SpiMock::new(&expected);
+    // high when idle, low around each register write
+    let mut states = vec![PinTransaction::set(State::High)];
+    for _ in 0..expected.len() {
+        states.extend([
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+        ]);
+    }
+    let mut cs = PinMock::new(&states);
+
+    let mut max = Max7219::<_, _, 8>::new(spi.clone(), cs.clone()).unwrap();
+    max.write(0, &[Segment::A, Segment::G | Segment::DP])
+        .unwrap();
+    max.set_brightness(200).unwrap();
+
+    spi.done();
+    cs.done();
+}
+
+#[test]
+fn tube_writes_through_driver() {
+    let mut tube = Tube::new(
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::High)])),
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::Low)])),
+    )
+    .unwrap();
+
+    assert_eq!(tube.digits(), 4);
+    tube.write_tube(0, Text::new("Hi").unwrap()).unwrap();
+    tube.write(3, &[Segment::A, Segment::B]).unwrap();
+
+    let (segments, digits) = tube.release();
+    segments
+        .into_iter()
+        .chain(digits)
+        .for_each(|mut p| p.done());
+}
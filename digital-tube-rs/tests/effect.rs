@@ -0,0 +1,40 @@
+use digital_tube_rs::{Error, Marquee, SegDisplay, Text, TubeDisplay, MARQUEE_LEN};
+
+fn text(s: &str) -> [u8; 4] {
+    Text::new(s).unwrap().tube_bits().map(|s| s.bits())
+}
+
+#[test]
+fn short_marquee_stands_still() {
+    let mut marquee = Marquee::new("Hi").unwrap();
+    assert!(!marquee.scrolls());
+    marquee.step();
+    assert_eq!(marquee.frame(), text("Hi"));
+}
+
+#[test]
+fn marquee_scrolls_left_and_comes_round() {
+    let mut marquee = Marquee::new("HELLO.").unwrap();
+    assert_eq!(marquee.len(), 5);
+    assert_eq!(marquee.period(), 9);
+
+    let mut frames = Vec::new();
+    for _ in 0..marquee.period() {
+        frames.push(marquee.frame());
+        marquee.step();
+    }
+    assert_eq!(frames[0], text("HELL"));
+    assert_eq!(frames[1], text("ELLO."));
+    assert_eq!(frames[2], text("LLO."));
+    assert_eq!(frames[5], text(""));
+    assert_eq!(frames[6], text("   H"));
+    assert_eq!(frames[8], text(" HEL"));
+    assert_eq!(marquee.frame(), text("HELL"));
+}
+
+#[test]
+fn marquee_rejects_long_or_unsupported_text() {
+    let long = "8".repeat(MARQUEE_LEN + 1);
+    assert_eq!(Marquee::new(&long), Err(Error::TooLong));
+    assert_eq!(Marquee::new("WAKE"), Err(Error::Unsupported('W')));
+}
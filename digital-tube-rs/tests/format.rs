@@ -0,0 +1,53 @@
+use digital_tube_rs::{Fixed, Float, SegDisplay, Signed, Text, TubeDisplay, OVERFLOW};
+
+fn bits(tube: impl TubeDisplay) -> [u8; 4] {
+    tube.tube_bits().map(|s| s.bits())
+}
+
+fn text(s: &str) -> [u8; 4] {
+    bits(Text::new(s).unwrap())
+}
+
+#[test]
+fn signed_is_right_aligned_with_minus() {
+    assert_eq!(bits(Signed::new(42)), text("  42"));
+    assert_eq!(bits(Signed::new(-7)), text("  -7"));
+    assert_eq!(bits(Signed::new(0)), text("   0"));
+    assert_eq!(bits(Signed::new(-999)), text("-999"));
+    assert_eq!(bits(Signed::new(9999)), text("9999"));
+}
+
+#[test]
+fn signed_overflows_past_four_digits() {
+    assert_eq!(bits(Signed::new(10000)), OVERFLOW);
+    assert_eq!(bits(Signed::new(-1000)), OVERFLOW);
+    assert_eq!(bits(Signed::new(i32::MIN)), OVERFLOW);
+}
+
+#[test]
+fn fixed_places_point_and_rounds_off_decimals() {
+    assert_eq!(bits(Fixed::new(2345, 2)), text("23.45"));
+    assert_eq!(bits(Fixed::new(-2345, 2)), text("-23.5"));
+    assert_eq!(bits(Fixed::new(5, 2)), text(" 0.05"));
+    assert_eq!(bits(Fixed::new(-5, 1)), text(" -0.5"));
+    assert_eq!(bits(Fixed::new(123456, 3)), text("123.5"));
+    assert_eq!(bits(Fixed::new(99996, 1)), OVERFLOW);
+    assert_eq!(bits(Fixed::new(12345, 0)), OVERFLOW);
+}
+
+#[test]
+fn float_uses_as_many_decimals_as_fit() {
+    assert_eq!(bits(Float::new(1.23456)), text("1.235"));
+    assert_eq!(bits(Float::new(-1.23456)), text("-1.23"));
+    assert_eq!(bits(Float::new(25.0)), text("25.00"));
+    assert_eq!(bits(Float::new(1234.4)), text("1234"));
+    assert_eq!(bits(Float::new(0.0004)), text("0.000"));
+}
+
+#[test]
+fn float_overflows_out_of_range() {
+    assert_eq!(bits(Float::new(12345.0)), OVERFLOW);
+    assert_eq!(bits(Float::new(9999.6)), OVERFLOW);
+    assert_eq!(bits(Float::new(f32::NAN)), OVERFLOW);
+    assert_eq!(bits(Float::new(f32::NEG_INFINITY)), OVERFLOW);
+}
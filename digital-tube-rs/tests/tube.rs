@@ -0,0 +1,230 @@
+use digital_tube_rs::{Marquee, Number, Polarity, Segment, SegmentPort, Tube, BRIGHTNESS_LEVELS};
+use embedded_hal_mock::eh0::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+
+fn pin(states: &[State]) -> PinMock {
+    let expected: Vec<_> = states.iter().map(|s| PinTransaction::set(*s)).collect();
+    PinMock::new(&expected)
+}
+
+/// One multiplex slot, a digit at the configured brightness.
+fn slot<S, D>(tube: &mut Tube<S, D>)
+where
+    S: SegmentPort,
+    D: embedded_hal::digital::v2::OutputPin<Error = S::Error>,
+    S::Error: core::fmt::Debug,
+{
+    (0..BRIGHTNESS_LEVELS).for_each(|_| tube.refresh().unwrap());
+}
+
+fn done(mut pins: Vec<PinMock>) {
+    pins.iter_mut().for_each(|p| p.done());
+}
+
+#[test]
+fn refresh_lights_one_digit_at_a_time() {
+    use State::{High, Low};
+
+    // new() turns all segments off (high), then two slots show
+    // digit 0 with segment A and digit 1 with segment B
+    let mut segments: Vec<_> = (0..8)
+        .map(|i| match i {
+            0 => pin(&[High, Low, High]),
+            1 => pin(&[High, High, Low]),
+            _ => pin(&[High, High, High]),
+        })
+        .collect();
+    let mut digits = vec![
+        pin(&[Low, High, Low]),
+        pin(&[Low, High]),
+        pin(&[Low]),
+        pin(&[Low]),
+    ];
+    let mut tube = Tube::new(
+        core::array::from_fn(|i| segments[i].clone()),
+        core::array::from_fn(|i| digits[i].clone()),
+    )
+    .unwrap();
+
+    tube.set_tube([Segment::A, Segment::B, 0, 0]);
+    slot(&mut tube);
+    slot(&mut tube);
+
+    segments.append(&mut digits);
+    done(segments);
+}
+
+#[test]
+fn refresh_wraps_after_last_digit() {
+    use State::{High, Low};
+
+    // digit 0 lit in the first and the fifth slot
+    let segments: Vec<_> = (0..8).map(|_| pin(&[High; 6])).collect();
+    let mut digits = vec![pin(&[Low, High, Low, High])];
+    digits.extend((0..2).map(|_| pin(&[Low, High, Low])));
+    digits.push(pin(&[Low, High, Low]));
+    let mut tube = Tube::new(
+        core::array::from_fn(|i| segments[i].clone()),
+        core::array::from_fn(|i| digits[i].clone()),
+    )
+    .unwrap();
+
+    (0..5).for_each(|_| slot(&mut tube));
+
+    done(segments.into_iter().chain(digits).collect());
+}
+
+#[test]
+fn brightness_cuts_the_slot_short() {
+    use State::{High, Low};
+
+    // digit 0 at 2 levels is turned off on the third step, digit 1 at 0 never lights
+    let segments: Vec<_> = (0..8).map(|_| pin(&[High; 3])).collect();
+    let digits = vec![
+        pin(&[Low, High, Low]),
+        pin(&[Low]),
+        pin(&[Low]),
+        pin(&[Low]),
+    ];
+    let mut tube = Tube::new(
+        core::array::from_fn(|i| segments[i].clone()),
+        core::array::from_fn(|i| digits[i].clone()),
+    )
+    .unwrap();
+
+    tube.set_brightness(0);
+    tube.set_digit_brightness(0, 2);
+    assert_eq!(tube.brightness(1), 0);
+    tube.refresh().unwrap();
+    tube.refresh().unwrap();
+    tube.refresh().unwrap();
+    slot(&mut tube);
+
+    done(segments.into_iter().chain(digits).collect());
+}
+
+#[test]
+fn brightness_is_clamped() {
+    let mut tube = Tube::new(
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::High)])),
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::Low)])),
+    )
+    .unwrap();
+
+    tube.set_digit_brightness(2, 200);
+    assert_eq!(tube.brightness(2), BRIGHTNESS_LEVELS);
+    let (segments, digits) = tube.release();
+    done(segments.into_iter().chain(digits).collect());
+}
+
+#[test]
+fn blink_mask_blanks_digits_every_other_period() {
+    use State::{High, Low};
+
+    // digit 0 shows segment A and blinks every 2 scans, the first toggle
+    // comes on the second scan after set_blink
+    let mut a = vec![High];
+    for on in [true, false, false, true] {
+        a.extend([if on { Low } else { High }, High, High, High]);
+    }
+    let mut segments = vec![pin(&a)];
+    segments.extend((1..8).map(|_| pin(&[High; 17])));
+    let mut dig = vec![Low];
+    (0..4).for_each(|_| dig.extend([High, Low]));
+    let mut digits: Vec<_> = (0..3).map(|_| pin(&dig)).collect();
+    digits.push(pin(&dig[..8]));
+    let mut tube = Tube::new(
+        core::array::from_fn(|i| segments[i].clone()),
+        core::array::from_fn(|i| digits[i].clone()),
+    )
+    .unwrap();
+
+    tube.set_tube([Segment::A, 0, 0, 0]);
+    tube.set_blink_speed(2);
+    tube.set_blink(0b0001);
+    (0..16).for_each(|_| slot(&mut tube));
+
+    segments.append(&mut digits);
+    done(segments);
+}
+
+#[test]
+fn scroll_runs_until_something_else_is_shown() {
+    let mut tube = Tube::new(
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::High)])),
+        core::array::from_fn(|_| PinMock::new(&[PinTransaction::set(State::Low)])),
+    )
+    .unwrap();
+
+    tube.scroll(Marquee::new("Hi").unwrap());
+    assert!(!tube.is_scrolling());
+    tube.scroll(Marquee::new("HELLO").unwrap());
+    assert!(tube.is_scrolling());
+    tube.set_tube(Number::new(1));
+    assert!(!tube.is_scrolling());
+
+    let (segments, digits) = tube.release();
+    done(segments.into_iter().chain(digits).collect());
+}
+
+#[test]
+fn common_cathode_inverts_both_sides() {
+    use State::{High, Low};
+
+    // segment A lit high on digit 0, digit 0 selected low
+    let segments: Vec<_> = (0..8)
+        .map(|i| match i {
+            0 => pin(&[Low, High]),
+            _ => pin(&[Low, Low]),
+        })
+        .collect();
+    let digits = vec![pin(&[High, Low]), pin(&[High]), pin(&[High]), pin(&[High])];
+    let mut tube = Tube::with_polarity(
+        core::array::from_fn(|i| segments[i].clone()),
+        core::array::from_fn(|i| digits[i].clone()),
+        Polarity::COMMON_CATHODE,
+    )
+    .unwrap();
+
+    tube.set_tube([Segment::A, 0, 0, 0]);
+    slot(&mut tube);
+
+    done(segments.into_iter().chain(digits).collect());
+}
+
+/// Port taking all segment levels in one write, like a BSRR.
+struct Port(Vec<u8>);
+
+impl SegmentPort for Port {
+    type Error = embedded_hal_mock::eh0::MockError;
+
+    fn write_levels(&mut self, levels: u8) -> Result<(), Self::Error> {
+        self.0.push(levels);
+        Ok(())
+    }
+}
+
+#[test]
+fn segment_port_gets_one_write_per_digit() {
+    use State::{High, Low};
+
+    let digits = vec![
+        pin(&[Low, High, Low]),
+        pin(&[Low, High]),
+        pin(&[Low]),
+        pin(&[Low]),
+    ];
+    let mut tube = Tube::new(
+        Port(Vec::new()),
+        core::array::from_fn(|i| digits[i].clone()),
+    )
+    .unwrap();
+
+    tube.set_tube([Segment::A, Segment::B | Segment::DP, 0, 0]);
+    slot(&mut tube);
+    slot(&mut tube);
+
+    let (port, _) = tube.release();
+    // common anode, lit segments are the low levels
+    assert_eq!(port.0, [0xFF, !Segment::A, !(Segment::B | Segment::DP)]);
+    done(digits);
+}
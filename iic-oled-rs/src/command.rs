@@ -0,0 +1,358 @@
+//! Command sequences shared by the blocking and async drivers.
+
+use core::iter;
+
+use heapless::Vec;
+
+use crate::config::{Config, Vcc};
+use crate::consts::{self, SSD1306Cmd};
+use crate::controller::{Controller, HardwareScroll};
+use crate::power::{ContrastFade, PixelShift};
+use crate::scroll::{Scroll, ScrollDirection};
+use crate::Error;
+
+/// One command with its parameters.
+pub(crate) type Command = Vec<u8, { consts::CMD_BUFFER_SIZE }>;
+/// Commands are sent one by one so a transfer never splits one.
+pub(crate) type Commands = Vec<Command, 24>;
+
+/// One step of a write sequence, the front ends send them in order.
+pub(crate) enum Transfer<'a> {
+    Cmd(Command),
+    Data(&'a [u8]),
+    /// Data built on the way, like a transposed page.
+    Page([u8; consts::DISPLAY_WIDTH]),
+}
+
+static ZEROS: [u8; consts::DATA_BUFFER_SIZE] = [0x00; consts::DATA_BUFFER_SIZE];
+
+fn push(cmds: &mut Commands, cmd: &[u8]) {
+    cmds.push(Command::from_slice(cmd).unwrap()).unwrap();
+}
+
+pub(crate) fn init<E, const H: usize, C: Controller>(
+    config: &Config,
+) -> Result<Commands, Error<E>> {
+    if !H.is_multiple_of(8) || !(16..=64).contains(&H) {
+        return Err(Error::InvalidGeometry);
+    }
+
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::DISPLAY_OFF]);
+    push(
+        &mut cmds,
+        &[SSD1306Cmd::SET_DISPLAY_CLOCK_DIV, config.clock],
+    );
+    push(&mut cmds, &[SSD1306Cmd::SET_MULTIPLEX, H as u8 - 1]);
+    push(&mut cmds, &[SSD1306Cmd::SET_DISPLAY_OFFSET, 0x00]);
+    if let Some(charge_pump) = C::charge_pump(config.vcc == Vcc::Internal) {
+        push(&mut cmds, &charge_pump);
+    }
+    push(&mut cmds, &[SSD1306Cmd::SET_DISPLAY_START_LINE]);
+    let (seg_remap, com_scan) = config.scan_direction();
+    push(&mut cmds, &[seg_remap]);
+    push(&mut cmds, &[com_scan]);
+    // panels of 32 rows or less use sequential COM pins
+    let com_pins = if H > 32 { 0x12 } else { 0x02 };
+    push(&mut cmds, &[SSD1306Cmd::SET_COM_PINS, com_pins]);
+    push(&mut cmds, &[SSD1306Cmd::SET_CONTRAST, config.contrast]);
+    push(&mut cmds, &[SSD1306Cmd::SET_PRECHARGE, config.precharge]);
+    push(&mut cmds, &[SSD1306Cmd::SET_VCOM_DETECT, config.vcomh]);
+    push(&mut cmds, &[SSD1306Cmd::DISPLAY_ALL_ON_RESUME]);
+    push(&mut cmds, &[SSD1306Cmd::NORMAL_DISPLAY]);
+    push(&mut cmds, &[SSD1306Cmd::DISPLAY_ON]);
+    Ok(cmds)
+}
+
+/// Display off, then the charge pump off if it was switched on by `init`.
+pub(crate) fn sleep<C: Controller>(config: &Config) -> Commands {
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::DISPLAY_OFF]);
+    if config.vcc == Vcc::Internal {
+        if let Some(charge_pump) = C::charge_pump(false) {
+            push(&mut cmds, &charge_pump);
+        }
+    }
+    cmds
+}
+
+pub(crate) fn wake<C: Controller>(config: &Config) -> Commands {
+    let mut cmds = Commands::new();
+    if config.vcc == Vcc::Internal {
+        if let Some(charge_pump) = C::charge_pump(true) {
+            push(&mut cmds, &charge_pump);
+        }
+    }
+    push(&mut cmds, &[SSD1306Cmd::DISPLAY_ON]);
+    cmds
+}
+
+pub(crate) fn contrast(contrast: u8) -> Commands {
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::SET_CONTRAST, contrast]);
+    cmds
+}
+
+pub(crate) fn display_offset<E, const H: usize>(rows: u8) -> Result<Commands, Error<E>> {
+    if rows as usize >= H {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::SET_DISPLAY_OFFSET, rows]);
+    Ok(cmds)
+}
+
+pub(crate) fn start_line<E, const H: usize>(line: u8) -> Result<Commands, Error<E>> {
+    if line as usize >= H {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::SET_DISPLAY_START_LINE | line]);
+    Ok(cmds)
+}
+
+/// Contrast levels of a fade, `true` for those sent after a wait.
+pub(crate) fn fade_contrast(from: u8, to: u8, step: u8) -> impl Iterator<Item = (bool, u8)> {
+    ContrastFade::new(from, to, step)
+        .enumerate()
+        .map(|(i, contrast)| (i > 0, contrast))
+}
+
+/// Counts a tick of `shift`, the start line to send when it is due.
+pub(crate) fn pixel_shift<E, const H: usize>(shift: &mut PixelShift) -> Result<Commands, Error<E>> {
+    if shift.max_rows() as usize >= H {
+        return Err(Error::OutOfRange);
+    }
+    match shift.tick() {
+        Some(line) => start_line::<E, H>(line),
+        None => Ok(Commands::new()),
+    }
+}
+
+pub(crate) fn memory_mode(mode: u8) -> Commands {
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::MEMORY_MODE, mode]);
+    cmds
+}
+
+pub(crate) fn display_addr<E, const H: usize>(
+    col: (u8, u8),
+    page: (u8, u8),
+) -> Result<Commands, Error<E>> {
+    if col.0 > col.1
+        || col.1 as usize >= consts::DISPLAY_WIDTH
+        || page.0 > page.1
+        || page.1 as usize >= H / 8
+    {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::SET_COLUMN_ADDR, col.0, col.1]);
+    push(&mut cmds, &[SSD1306Cmd::SET_PAGE_ADDR, page.0, page.1]);
+    Ok(cmds)
+}
+
+#[inline(always)]
+pub(crate) fn full_window<E, const H: usize>() -> Result<Commands, Error<E>> {
+    display_addr::<E, H>((0, consts::DISPLAY_WIDTH as u8 - 1), (0, (H / 8) as u8 - 1))
+}
+
+pub(crate) fn page_column<E, const H: usize, C: Controller>(
+    page: u8,
+    col: u8,
+) -> Result<Commands, Error<E>> {
+    if page as usize >= H / 8 || col as usize >= C::RAM_WIDTH {
+        return Err(Error::OutOfRange);
+    }
+
+    Ok(set_page_column(page as usize, col).into_iter().collect())
+}
+
+/// `page_column` for a position already known to be on the panel.
+fn set_page_column(page: usize, col: u8) -> [Command; 3] {
+    [
+        SSD1306Cmd::SET_PAGE_START | (page as u8 & 0x07),
+        SSD1306Cmd::SET_LOW_COLUMN | (col & 0x0F),
+        SSD1306Cmd::SET_HIGH_COLUMN | (col >> 4),
+    ]
+    .map(|cmd| Command::from_slice(&[cmd]).unwrap())
+}
+
+/// Commands of `cmds` one by one, then `data`.
+fn with_data<'a>(
+    cmds: impl IntoIterator<Item = Command>,
+    data: Transfer<'a>,
+) -> impl Iterator<Item = Transfer<'a>> {
+    cmds.into_iter().map(Transfer::Cmd).chain(iter::once(data))
+}
+
+/// Zeros over every RAM column, page by page so it works on any controller.
+pub(crate) fn clear<const H: usize, C: Controller>() -> impl Iterator<Item = Transfer<'static>> {
+    (0..H / 8).flat_map(|page| {
+        let zeros = (0..C::RAM_WIDTH)
+            .step_by(ZEROS.len())
+            .map(|col| Transfer::Data(&ZEROS[..(C::RAM_WIDTH - col).min(ZEROS.len())]));
+        set_page_column(page, 0)
+            .into_iter()
+            .map(Transfer::Cmd)
+            .chain(zeros)
+    })
+}
+
+/// A whole frame laid out page by page, `DISPLAY_WIDTH` bytes per page.
+pub(crate) fn draw<E, const H: usize, C: Controller>(
+    frame: &[u8],
+) -> Result<impl Iterator<Item = Transfer<'_>>, Error<E>> {
+    check_frame::<E, H>(frame)?;
+    let window = window::<E, H, C>(frame, 0x00)?;
+    let pages = (!C::ADDRESSING_MODES).then(|| {
+        frame
+            .chunks(consts::DISPLAY_WIDTH)
+            .enumerate()
+            .flat_map(|(page, data)| {
+                with_data(
+                    set_page_column(page, C::COLUMN_OFFSET as u8),
+                    Transfer::Data(data),
+                )
+            })
+    });
+    Ok(window
+        .into_iter()
+        .flatten()
+        .chain(pages.into_iter().flatten()))
+}
+
+/// A whole frame laid out column by column, `H / 8` bytes per column.
+pub(crate) fn draw_vertical<E, const H: usize, C: Controller>(
+    frame: &[u8],
+) -> Result<impl Iterator<Item = Transfer<'_>>, Error<E>> {
+    check_frame::<E, H>(frame)?;
+    let window = window::<E, H, C>(frame, 0x01)?;
+    // no vertical addressing, transpose into one page at a time
+    let pages = (!C::ADDRESSING_MODES).then(|| {
+        (0..H / 8).flat_map(move |page| {
+            let mut buffer = [0; consts::DISPLAY_WIDTH];
+            transpose_page::<H>(frame, page, &mut buffer);
+            with_data(
+                set_page_column(page, C::COLUMN_OFFSET as u8),
+                Transfer::Page(buffer),
+            )
+        })
+    });
+    Ok(window
+        .into_iter()
+        .flatten()
+        .chain(pages.into_iter().flatten()))
+}
+
+/// Memory mode `mode` over the full window then `frame` in one go,
+/// `None` on controllers without addressing modes.
+fn window<E, const H: usize, C: Controller>(
+    frame: &[u8],
+    mode: u8,
+) -> Result<Option<impl Iterator<Item = Transfer<'_>>>, Error<E>> {
+    if !C::ADDRESSING_MODES {
+        return Ok(None);
+    }
+
+    let cmds = memory_mode(mode).into_iter().chain(full_window::<E, H>()?);
+    Ok(Some(with_data(cmds, Transfer::Data(frame))))
+}
+
+pub(crate) fn start_scroll<E, const H: usize, C: HardwareScroll>(
+    scroll: &Scroll,
+) -> Result<Commands, Error<E>> {
+    let (start, end) = scroll.pages;
+    if start > end || end as usize >= H / 8 || scroll.vertical_offset as usize >= H {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut cmds = Commands::new();
+    // scrolling must be off while it is set up
+    push(&mut cmds, &[SSD1306Cmd::DEACTIVATE_SCROLL]);
+    let interval = scroll.interval.bits();
+    let mut setup = if scroll.vertical_offset == 0 {
+        let cmd = match scroll.direction {
+            ScrollDirection::Right => SSD1306Cmd::RIGHT_HORIZONTAL_SCROLL,
+            ScrollDirection::Left => SSD1306Cmd::LEFT_HORIZONTAL_SCROLL,
+        };
+        Command::from_slice(&[cmd, 0x00, start, interval, end, 0x00]).unwrap()
+    } else {
+        let cmd = match scroll.direction {
+            ScrollDirection::Right => SSD1306Cmd::VERTICAL_RIGHT_HORIZONTAL_SCROLL,
+            ScrollDirection::Left => SSD1306Cmd::VERTICAL_LEFT_HORIZONTAL_SCROLL,
+        };
+        // the SSD1309 takes a flag turning the horizontal part on
+        let horizontal = C::SCROLL_COLUMNS as u8;
+        Command::from_slice(&[
+            cmd,
+            horizontal,
+            start,
+            interval,
+            end,
+            scroll.vertical_offset,
+        ])
+        .unwrap()
+    };
+    if C::SCROLL_COLUMNS {
+        setup
+            .extend_from_slice(&[0x00, consts::DISPLAY_WIDTH as u8 - 1])
+            .unwrap();
+    } else if scroll.vertical_offset == 0 {
+        setup.push(0xFF).unwrap();
+    }
+    cmds.push(setup).unwrap();
+    push(&mut cmds, &[SSD1306Cmd::ACTIVATE_SCROLL]);
+    Ok(cmds)
+}
+
+pub(crate) fn stop_scroll() -> Commands {
+    let mut cmds = Commands::new();
+    push(&mut cmds, &[SSD1306Cmd::DEACTIVATE_SCROLL]);
+    cmds
+}
+
+pub(crate) fn vertical_scroll_area<E, const H: usize>(
+    fixed_rows: u8,
+    scroll_rows: u8,
+) -> Result<Commands, Error<E>> {
+    if fixed_rows as usize + scroll_rows as usize > H {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut cmds = Commands::new();
+    push(
+        &mut cmds,
+        &[
+            SSD1306Cmd::SET_VERTICAL_SCROLL_AREA,
+            fixed_rows,
+            scroll_rows,
+        ],
+    );
+    Ok(cmds)
+}
+
+#[inline(always)]
+fn check_frame<E, const H: usize>(frame: &[u8]) -> Result<(), Error<E>> {
+    if frame.len() > consts::DISPLAY_WIDTH * H / 8 {
+        return Err(Error::OutOfRange);
+    }
+    Ok(())
+}
+
+/// Collects one page of a column by column frame.
+fn transpose_page<const H: usize>(
+    frame: &[u8],
+    page: usize,
+    buffer: &mut [u8; consts::DISPLAY_WIDTH],
+) {
+    frame
+        .iter()
+        .skip(page)
+        .step_by(H / 8)
+        .zip(buffer.iter_mut())
+        .for_each(|(src, dst)| *dst = *src);
+}
@@ -0,0 +1,118 @@
+use crate::consts::SSD1306Cmd;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Deg0,
+    Deg180,
+}
+
+/// Where the panel's VCC comes from, `Internal` switches the charge pump on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vcc {
+    Internal,
+    External,
+}
+
+/// Settings applied by `Oled::init`, the defaults match the usual 0.96" modules.
+///
+/// ```
+/// use iic_oled_rs::{Config, Rotation, Vcc};
+///
+/// let config = Config::new()
+///     .rotation(Rotation::Deg180)
+///     .vcc(Vcc::External)
+///     .contrast(0x9F)
+///     .precharge(0x22);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub(crate) rotation: Rotation,
+    pub(crate) mirror_x: bool,
+    pub(crate) mirror_y: bool,
+    pub(crate) contrast: u8,
+    pub(crate) precharge: u8,
+    pub(crate) vcomh: u8,
+    pub(crate) vcc: Vcc,
+    pub(crate) clock: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            rotation: Rotation::Deg0,
+            mirror_x: false,
+            mirror_y: false,
+            contrast: 0xCF,
+            precharge: 0xF1,
+            vcomh: 0x40,
+            vcc: Vcc::Internal,
+            clock: 0x80,
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Flips columns, on top of the rotation.
+    pub fn mirror_x(mut self, mirror: bool) -> Self {
+        self.mirror_x = mirror;
+        self
+    }
+
+    /// Flips rows, on top of the rotation.
+    pub fn mirror_y(mut self, mirror: bool) -> Self {
+        self.mirror_y = mirror;
+        self
+    }
+
+    pub fn contrast(mut self, contrast: u8) -> Self {
+        self.contrast = contrast;
+        self
+    }
+
+    /// Phase 2 period in the high nibble, phase 1 in the low one, in DCLKs.
+    pub fn precharge(mut self, precharge: u8) -> Self {
+        self.precharge = precharge;
+        self
+    }
+
+    /// Raw `SET_VCOM_DETECT` level, e.g. 0x00, 0x20 or 0x30 for 0.65, 0.77 or 0.83 VCC.
+    pub fn vcomh(mut self, vcomh: u8) -> Self {
+        self.vcomh = vcomh;
+        self
+    }
+
+    pub fn vcc(mut self, vcc: Vcc) -> Self {
+        self.vcc = vcc;
+        self
+    }
+
+    /// `divide` in `1..=16`, `oscillator` frequency setting in `0..=15`.
+    pub fn clock(mut self, divide: u8, oscillator: u8) -> Self {
+        self.clock = (oscillator & 0x0F) << 4 | (divide.clamp(1, 16) - 1);
+        self
+    }
+
+    /// `SEG_REMAP` and COM scan direction commands for the rotation and mirroring.
+    pub(crate) fn scan_direction(&self) -> (u8, u8) {
+        let flipped = self.rotation == Rotation::Deg180;
+        let seg_remap = (!flipped) ^ self.mirror_x;
+        let com_dec = (!flipped) ^ self.mirror_y;
+        (
+            SSD1306Cmd::SEG_REMAP | seg_remap as u8,
+            if com_dec {
+                SSD1306Cmd::COM_SCAN_DEC
+            } else {
+                SSD1306Cmd::COM_SCAN_INC
+            },
+        )
+    }
+}
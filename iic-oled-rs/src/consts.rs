@@ -1,4 +1,7 @@
+/// Address with the module's D/C pin strapped low, the usual default.
 pub const SSD1306_ADDR: u8 = 0x3C;
+/// Address with D/C strapped high.
+pub const SSD1306_ADDR_ALT: u8 = 0x3D;
 
 pub struct SSD1306Cmd;
 
@@ -27,7 +30,27 @@ impl SSD1306Cmd {
     pub const SEG_REMAP: u8 = 0xA0;
     pub const SET_COM_PINS_ALT: u8 = 0xDA;
     pub const SET_DISPLAY_START_LINE: u8 = 0x40;
+    pub const SET_LOW_COLUMN: u8 = 0x00;
+    pub const SET_HIGH_COLUMN: u8 = 0x10;
+    pub const SET_PAGE_START: u8 = 0xB0;
+    pub const NOP: u8 = 0xE3;
+    pub const RIGHT_HORIZONTAL_SCROLL: u8 = 0x26;
+    pub const LEFT_HORIZONTAL_SCROLL: u8 = 0x27;
+    pub const VERTICAL_RIGHT_HORIZONTAL_SCROLL: u8 = 0x29;
+    pub const VERTICAL_LEFT_HORIZONTAL_SCROLL: u8 = 0x2A;
+    pub const DEACTIVATE_SCROLL: u8 = 0x2E;
+    pub const ACTIVATE_SCROLL: u8 = 0x2F;
+    pub const SET_VERTICAL_SCROLL_AREA: u8 = 0xA3;
 }
 
-pub const CMD_BUFFER_SIZE: usize = 4;
+pub struct SH1106Cmd;
+
+impl SH1106Cmd {
+    pub const SET_DC_DC: u8 = 0xAD;
+    pub const DC_DC_ON: u8 = 0x8B;
+    pub const DC_DC_OFF: u8 = 0x8A;
+}
+
+pub const CMD_BUFFER_SIZE: usize = 8;
 pub const DATA_BUFFER_SIZE: usize = 128;
+pub const DISPLAY_WIDTH: usize = 128;
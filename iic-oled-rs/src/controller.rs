@@ -0,0 +1,82 @@
+use crate::consts::{SH1106Cmd, SSD1306Cmd};
+
+/// Display controller driven by [`Oled`](crate::Oled), selected at type level.
+pub trait Controller {
+    /// Columns of display RAM, visible or not.
+    const RAM_WIDTH: usize;
+    /// RAM column wired to the leftmost pixel of the panel.
+    const COLUMN_OFFSET: usize;
+    /// Command switching the internal charge pump on or off, `None` if there is none.
+    fn charge_pump(on: bool) -> Option<[u8; 2]>;
+    /// Whether `MEMORY_MODE` is supported, otherwise data is written page by page.
+    const ADDRESSING_MODES: bool;
+}
+
+/// Marker for controllers with horizontal/vertical addressing modes.
+pub trait AddressingModes: Controller {}
+
+/// Marker for controllers with continuous hardware scrolling.
+pub trait HardwareScroll: Controller {
+    /// Whether scroll setups end with a start and end column, as on the SSD1309,
+    /// rather than the SSD1306 dummy bytes.
+    const SCROLL_COLUMNS: bool = false;
+}
+
+pub struct Ssd1306;
+
+impl Controller for Ssd1306 {
+    const RAM_WIDTH: usize = 128;
+    const COLUMN_OFFSET: usize = 0;
+    const ADDRESSING_MODES: bool = true;
+
+    fn charge_pump(on: bool) -> Option<[u8; 2]> {
+        let state = if on {
+            SSD1306Cmd::CHARGE_PUMP_ENABLE
+        } else {
+            SSD1306Cmd::CHARGE_PUMP_DISABLE
+        };
+        Some([SSD1306Cmd::SET_CHARGE_PUMP, state])
+    }
+}
+
+impl AddressingModes for Ssd1306 {}
+
+impl HardwareScroll for Ssd1306 {}
+
+/// 132 column RAM with the 128 visible columns starting at column 2,
+/// page addressing only.
+pub struct Sh1106;
+
+impl Controller for Sh1106 {
+    const RAM_WIDTH: usize = 132;
+    const COLUMN_OFFSET: usize = 2;
+    const ADDRESSING_MODES: bool = false;
+
+    fn charge_pump(on: bool) -> Option<[u8; 2]> {
+        let state = if on {
+            SH1106Cmd::DC_DC_ON
+        } else {
+            SH1106Cmd::DC_DC_OFF
+        };
+        Some([SH1106Cmd::SET_DC_DC, state])
+    }
+}
+
+/// SSD1306 compatible command set, but without a charge pump.
+pub struct Ssd1309;
+
+impl Controller for Ssd1309 {
+    const RAM_WIDTH: usize = 128;
+    const COLUMN_OFFSET: usize = 0;
+    const ADDRESSING_MODES: bool = true;
+
+    fn charge_pump(_on: bool) -> Option<[u8; 2]> {
+        None
+    }
+}
+
+impl AddressingModes for Ssd1309 {}
+
+impl HardwareScroll for Ssd1309 {
+    const SCROLL_COLUMNS: bool = true;
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// The bus transfer failed.
+    Bus(E),
+    /// No panel acknowledged its address.
+    NotFound,
+    /// The panel height is not a multiple of 8 in `16..=64`.
+    InvalidGeometry,
+    /// The column/page window or frame is outside the panel.
+    OutOfRange,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Bus(e)
+    }
+}
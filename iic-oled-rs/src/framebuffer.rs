@@ -0,0 +1,124 @@
+use crate::consts::DISPLAY_WIDTH;
+
+/// Software rotation of a [`FrameBuffer`], clockwise.
+///
+/// `Deg90` and `Deg270` swap width and height, for portrait mounted panels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// In memory copy of the display RAM, `P` pages of `DISPLAY_WIDTH` columns.
+///
+/// Pixels are addressed in drawing coordinates, which the orientation and
+/// mirroring map onto the panel. Writes outside the drawing area are ignored.
+/// Send it with `Oled::flush`.
+pub struct FrameBuffer<const P: usize> {
+    buffer: [[u8; DISPLAY_WIDTH]; P],
+    orientation: Orientation,
+    mirror_x: bool,
+    mirror_y: bool,
+}
+
+impl<const P: usize> Default for FrameBuffer<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const P: usize> FrameBuffer<P> {
+    pub const fn new() -> Self {
+        FrameBuffer {
+            buffer: [[0; DISPLAY_WIDTH]; P],
+            orientation: Orientation::Deg0,
+            mirror_x: false,
+            mirror_y: false,
+        }
+    }
+
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Flips the drawing coordinates before they are rotated.
+    pub fn set_mirror(&mut self, mirror_x: bool, mirror_y: bool) {
+        self.mirror_x = mirror_x;
+        self.mirror_y = mirror_y;
+    }
+
+    /// Width and height of the drawing area.
+    pub fn size(&self) -> (usize, usize) {
+        match self.orientation {
+            Orientation::Deg0 | Orientation::Deg180 => (DISPLAY_WIDTH, P * 8),
+            Orientation::Deg90 | Orientation::Deg270 => (P * 8, DISPLAY_WIDTH),
+        }
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        let Some((col, row)) = self.map(x, y) else {
+            return;
+        };
+        let byte = &mut self.buffer[row / 8][col];
+        if on {
+            *byte |= 1 << (row % 8);
+        } else {
+            *byte &= !(1 << (row % 8));
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        self.map(x, y)
+            .is_some_and(|(col, row)| self.buffer[row / 8][col] >> (row % 8) & 1 == 1)
+    }
+
+    pub fn fill(&mut self, on: bool) {
+        let byte = if on { 0xFF } else { 0x00 };
+        self.buffer.iter_mut().for_each(|page| page.fill(byte));
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.fill(false);
+    }
+
+    /// Moves the drawing area up by `rows`, clearing the rows left at the bottom.
+    pub fn shift_up(&mut self, rows: usize) {
+        let (width, height) = self.size();
+        for y in 0..height {
+            for x in 0..width {
+                let on = y + rows < height && self.pixel(x, y + rows);
+                self.set_pixel(x, y, on);
+            }
+        }
+    }
+
+    /// Display RAM bytes, page by page.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.as_flattened()
+    }
+
+    /// Maps drawing coordinates to a panel column and row.
+    fn map(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        let (width, height) = self.size();
+        if x >= width || y >= height {
+            return None;
+        }
+        let x = if self.mirror_x { width - 1 - x } else { x };
+        let y = if self.mirror_y { height - 1 - y } else { y };
+
+        let (w, h) = (DISPLAY_WIDTH, P * 8);
+        Some(match self.orientation {
+            Orientation::Deg0 => (x, y),
+            Orientation::Deg90 => (w - 1 - y, x),
+            Orientation::Deg180 => (w - 1 - x, h - 1 - y),
+            Orientation::Deg270 => (y, h - 1 - x),
+        })
+    }
+}
@@ -0,0 +1,166 @@
+//! Interfaces over embedded-hal 0.2 blocking traits.
+
+use embedded_hal::{
+    blocking::{i2c::Write, spi::Write as SpiWrite},
+    digital::v2::OutputPin,
+};
+
+use super::{pack_words, Interface, SpiError};
+use crate::{consts, Error};
+
+/// I2C, every transfer prefixed with the control byte.
+pub struct I2cInterface<I> {
+    i2c: I,
+    addr: u8,
+}
+
+impl<I> I2cInterface<I> {
+    pub fn new(i2c: I) -> Self {
+        Self::with_addr(i2c, consts::SSD1306_ADDR)
+    }
+
+    pub fn with_addr(i2c: I, addr: u8) -> Self {
+        I2cInterface { i2c, addr }
+    }
+
+    pub fn addr(&self) -> u8 {
+        self.addr
+    }
+
+    pub fn release(self) -> I {
+        self.i2c
+    }
+}
+
+impl<I: Write> I2cInterface<I> {
+    fn write<const N: usize, const F: u8>(&mut self, data: &[u8]) -> Result<(), I::Error> {
+        super::write_with_control::<N, F, _>(data, |buffer| self.i2c.write(self.addr, buffer))
+    }
+}
+
+impl<I: Write> Interface for I2cInterface<I> {
+    type Error = I::Error;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write::<N, 0x00>(cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write::<N, 0x40>(data)
+    }
+
+    fn probe(&mut self) -> Result<(), Error<Self::Error>> {
+        // embedded-hal 0.2 can't tell a failed write from an address NACK
+        let i2c = &mut self.i2c;
+        self.addr = super::probe_addr(|addr, bytes| i2c.write(addr, bytes), |_| true)
+            .map_err(|_| Error::NotFound)?;
+        Ok(())
+    }
+}
+
+/// 4-wire SPI, D/C selected by the `dc` pin.
+pub struct SpiInterface<SPI, DC, CS> {
+    spi: SPI,
+    dc: DC,
+    cs: CS,
+}
+
+impl<SPI, DC, CS> SpiInterface<SPI, DC, CS>
+where
+    SPI: SpiWrite<u8>,
+    DC: OutputPin,
+    CS: OutputPin<Error = DC::Error>,
+{
+    pub fn new(spi: SPI, dc: DC, cs: CS) -> Self {
+        SpiInterface { spi, dc, cs }
+    }
+
+    pub fn release(self) -> (SPI, DC, CS) {
+        (self.spi, self.dc, self.cs)
+    }
+
+    fn write(&mut self, data: bool, bytes: &[u8]) -> Result<(), SpiError<SPI::Error, DC::Error>> {
+        if data {
+            self.dc.set_high()
+        } else {
+            self.dc.set_low()
+        }
+        .map_err(SpiError::Pin)?;
+        self.cs.set_low().map_err(SpiError::Pin)?;
+        let res = self.spi.write(bytes).map_err(SpiError::Spi);
+        self.cs.set_high().map_err(SpiError::Pin)?;
+        res
+    }
+}
+
+impl<SPI, DC, CS> Interface for SpiInterface<SPI, DC, CS>
+where
+    SPI: SpiWrite<u8>,
+    DC: OutputPin,
+    CS: OutputPin<Error = DC::Error>,
+{
+    type Error = SpiError<SPI::Error, DC::Error>;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write(false, cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write(true, data)
+    }
+}
+
+/// 3-wire SPI, every byte sent as a 9 bit word with the D/C bit in front.
+///
+/// Words are packed 8 at a time into 9 bytes for an 8 bit SPI peripheral,
+/// the last group is padded with `NOP` commands.
+pub struct Spi3WireInterface<SPI, CS> {
+    spi: SPI,
+    cs: CS,
+}
+
+impl<SPI, CS> Spi3WireInterface<SPI, CS>
+where
+    SPI: SpiWrite<u8>,
+    CS: OutputPin,
+{
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Spi3WireInterface { spi, cs }
+    }
+
+    pub fn release(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    fn write(&mut self, data: bool, bytes: &[u8]) -> Result<(), SpiError<SPI::Error, CS::Error>> {
+        self.cs.set_low().map_err(SpiError::Pin)?;
+        let res = bytes
+            .chunks(8)
+            .try_for_each(|c| self.spi.write(&pack_words(data, c)))
+            .map_err(SpiError::Spi);
+        self.cs.set_high().map_err(SpiError::Pin)?;
+        res
+    }
+}
+
+impl<SPI, CS> Interface for Spi3WireInterface<SPI, CS>
+where
+    SPI: SpiWrite<u8>,
+    CS: OutputPin,
+{
+    type Error = SpiError<SPI::Error, CS::Error>;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write(false, cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write(true, data)
+    }
+}
@@ -0,0 +1,191 @@
+//! Interfaces over embedded-hal 1.0 blocking traits, and embedded-hal-async for I2C.
+
+use embedded_hal_1::{
+    digital::{OutputPin, PinState},
+    i2c::{Error as I2cError, ErrorKind, I2c, Operation},
+    spi::SpiDevice,
+};
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::{I2c as AsyncI2c, Operation as AsyncOperation};
+
+#[cfg(feature = "async")]
+use super::AsyncInterface;
+use super::{pack_words, Interface, SpiError};
+use crate::{consts, Error};
+
+/// I2C, every transfer prefixed with the control byte.
+pub struct I2cInterface<I> {
+    i2c: I,
+    addr: u8,
+}
+
+impl<I> I2cInterface<I> {
+    pub fn new(i2c: I) -> Self {
+        Self::with_addr(i2c, consts::SSD1306_ADDR)
+    }
+
+    pub fn with_addr(i2c: I, addr: u8) -> Self {
+        I2cInterface { i2c, addr }
+    }
+
+    pub fn addr(&self) -> u8 {
+        self.addr
+    }
+
+    pub fn release(self) -> I {
+        self.i2c
+    }
+}
+
+impl<I: I2c> I2cInterface<I> {
+    /// Sends the control byte and each chunk of `data` as two writes of one transaction,
+    /// which embedded-hal 1.0 puts on the bus as a single transfer.
+    fn write<const N: usize, const F: u8>(&mut self, data: &[u8]) -> Result<(), I::Error> {
+        data.chunks(N - 1).try_for_each(|c| {
+            self.i2c.transaction(
+                self.addr,
+                &mut [Operation::Write(&[F]), Operation::Write(c)],
+            )
+        })
+    }
+}
+
+impl<I: I2c> Interface for I2cInterface<I> {
+    type Error = I::Error;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write::<N, 0x00>(cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write::<N, 0x40>(data)
+    }
+
+    fn probe(&mut self) -> Result<(), Error<Self::Error>> {
+        let i2c = &mut self.i2c;
+        self.addr = super::probe_addr(|addr, bytes| i2c.write(addr, bytes), is_nack)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I: AsyncI2c> I2cInterface<I> {
+    async fn write_async<const N: usize, const F: u8>(
+        &mut self,
+        data: &[u8],
+    ) -> Result<(), I::Error> {
+        for c in data.chunks(N - 1) {
+            self.i2c
+                .transaction(
+                    self.addr,
+                    &mut [AsyncOperation::Write(&[F]), AsyncOperation::Write(c)],
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I: AsyncI2c> AsyncInterface for I2cInterface<I> {
+    type Error = I::Error;
+
+    #[inline(always)]
+    async fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write_async::<N, 0x00>(cmds).await
+    }
+
+    #[inline(always)]
+    async fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write_async::<N, 0x40>(data).await
+    }
+
+    async fn probe(&mut self) -> Result<(), Error<Self::Error>> {
+        let i2c = &mut self.i2c;
+        self.addr =
+            super::probe_addr_async(async |addr, bytes| i2c.write(addr, bytes).await, is_nack)
+                .await?;
+        Ok(())
+    }
+}
+
+fn is_nack<E: I2cError>(e: &E) -> bool {
+    matches!(e.kind(), ErrorKind::NoAcknowledge(_))
+}
+
+/// 4-wire SPI, D/C selected by the `dc` pin, chip select handled by the `SpiDevice`.
+pub struct SpiInterface<SPI, DC> {
+    spi: SPI,
+    dc: DC,
+}
+
+impl<SPI: SpiDevice, DC: OutputPin> SpiInterface<SPI, DC> {
+    pub fn new(spi: SPI, dc: DC) -> Self {
+        SpiInterface { spi, dc }
+    }
+
+    pub fn release(self) -> (SPI, DC) {
+        (self.spi, self.dc)
+    }
+
+    fn write(&mut self, data: bool, bytes: &[u8]) -> Result<(), SpiError<SPI::Error, DC::Error>> {
+        self.dc
+            .set_state(PinState::from(data))
+            .map_err(SpiError::Pin)?;
+        self.spi.write(bytes).map_err(SpiError::Spi)
+    }
+}
+
+impl<SPI: SpiDevice, DC: OutputPin> Interface for SpiInterface<SPI, DC> {
+    type Error = SpiError<SPI::Error, DC::Error>;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write(false, cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write(true, data)
+    }
+}
+
+/// 3-wire SPI, every byte sent as a 9 bit word with the D/C bit in front.
+///
+/// Words are packed 8 at a time into 9 bytes for an 8 bit SPI peripheral,
+/// the last group is padded with `NOP` commands.
+pub struct Spi3WireInterface<SPI> {
+    spi: SPI,
+}
+
+impl<SPI: SpiDevice> Spi3WireInterface<SPI> {
+    pub fn new(spi: SPI) -> Self {
+        Spi3WireInterface { spi }
+    }
+
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+
+    fn write(&mut self, data: bool, bytes: &[u8]) -> Result<(), SPI::Error> {
+        bytes
+            .chunks(8)
+            .try_for_each(|c| self.spi.write(&pack_words(data, c)))
+    }
+}
+
+impl<SPI: SpiDevice> Interface for Spi3WireInterface<SPI> {
+    type Error = SPI::Error;
+
+    #[inline(always)]
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.write(false, cmds)
+    }
+
+    #[inline(always)]
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write(true, data)
+    }
+}
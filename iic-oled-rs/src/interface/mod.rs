@@ -0,0 +1,118 @@
+#[cfg(feature = "eh02")]
+pub mod eh02;
+#[cfg(feature = "eh1")]
+pub mod eh1;
+
+use crate::consts::{self, SSD1306Cmd};
+use crate::Error;
+
+/// Bus the command/data layer of [`Oled`](crate::Oled) is sent over.
+///
+/// `N` is the largest transfer the caller wants on the bus at once,
+/// an interface may split `bytes` into several transfers of up to `N` bytes.
+pub trait Interface {
+    type Error;
+
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error>;
+
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Checks that a panel is listening, buses without acknowledgement always succeed.
+    ///
+    /// I2C interfaces look for the panel with [`probe_addr`] and keep the address that answered.
+    fn probe(&mut self) -> Result<(), Error<Self::Error>> {
+        Ok(())
+    }
+}
+
+/// Async counterpart of [`Interface`], used by [`AsyncOled`](crate::AsyncOled).
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncInterface {
+    type Error;
+
+    async fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error>;
+
+    async fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Checks that a panel is listening, buses without acknowledgement always succeed.
+    ///
+    /// I2C interfaces look for the panel with [`probe_addr_async`] and keep the address that answered.
+    async fn probe(&mut self) -> Result<(), Error<Self::Error>> {
+        Ok(())
+    }
+}
+
+/// Sends a `NOP` command to `SSD1306_ADDR` then `SSD1306_ADDR_ALT` with `try_write`
+/// and returns the first address that takes it.
+///
+/// Errors `is_nack` accepts move on to the next address, any other is `Error::Bus`.
+pub fn probe_addr<E>(
+    mut try_write: impl FnMut(u8, &[u8]) -> Result<(), E>,
+    is_nack: impl Fn(&E) -> bool,
+) -> Result<u8, Error<E>> {
+    for addr in [consts::SSD1306_ADDR, consts::SSD1306_ADDR_ALT] {
+        match try_write(addr, &[0x80, SSD1306Cmd::NOP]) {
+            Ok(()) => return Ok(addr),
+            Err(e) if is_nack(&e) => continue,
+            Err(e) => return Err(Error::Bus(e)),
+        }
+    }
+    Err(Error::NotFound)
+}
+
+/// Async counterpart of [`probe_addr`].
+#[cfg(feature = "async")]
+pub async fn probe_addr_async<E>(
+    mut try_write: impl AsyncFnMut(u8, &[u8]) -> Result<(), E>,
+    is_nack: impl Fn(&E) -> bool,
+) -> Result<u8, Error<E>> {
+    for addr in [consts::SSD1306_ADDR, consts::SSD1306_ADDR_ALT] {
+        match try_write(addr, &[0x80, SSD1306Cmd::NOP]).await {
+            Ok(()) => return Ok(addr),
+            Err(e) if is_nack(&e) => continue,
+            Err(e) => return Err(Error::Bus(e)),
+        }
+    }
+    Err(Error::NotFound)
+}
+
+#[derive(Debug)]
+pub enum SpiError<S, P> {
+    Spi(S),
+    Pin(P),
+}
+
+/// Splits `data` into I2C transfers of up to `N` bytes, each starting with control byte `F`.
+///
+/// embedded-hal 0.2 has no scatter write every HAL implements, so each chunk is
+/// copied behind the control byte and only the filled part of `buffer` is sent.
+#[cfg(feature = "eh02")]
+fn write_with_control<const N: usize, const F: u8, E>(
+    data: &[u8],
+    mut write: impl FnMut(&[u8]) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut buffer = [0; N];
+    buffer[0] = F;
+
+    data.chunks(N - 1).try_for_each(|c| {
+        let len = c.len();
+        buffer[1..=len].copy_from_slice(c);
+        write(&buffer[..=len])
+    })
+}
+
+/// Packs up to 8 bytes into 9 bit words for 3-wire SPI, padding with `NOP` commands.
+#[cfg(any(feature = "eh02", feature = "eh1"))]
+fn pack_words(data: bool, bytes: &[u8]) -> [u8; 9] {
+    let words = bytes
+        .iter()
+        .map(|b| (data as u16) << 8 | *b as u16)
+        .chain(core::iter::repeat(SSD1306Cmd::NOP as u16))
+        .take(8);
+    let packed = words.fold(0u128, |acc, w| acc << 9 | w as u128);
+
+    let mut buffer = [0; 9];
+    buffer.copy_from_slice(&packed.to_be_bytes()[16 - 9..]);
+    buffer
+}
@@ -1,6 +1,29 @@
 #![no_std]
 
+mod command;
+pub mod config;
 pub mod consts;
+pub mod controller;
+mod error;
+pub mod framebuffer;
+pub mod interface;
 mod oled;
+#[cfg(feature = "async")]
+mod oled_async;
+pub mod power;
+pub mod scroll;
+pub mod text;
 
+pub use config::{Config, Rotation, Vcc};
+pub use controller::{Sh1106, Ssd1306, Ssd1309};
+pub use error::Error;
+pub use framebuffer::{FrameBuffer, Orientation};
+#[cfg(feature = "eh02")]
+pub use interface::eh02::{I2cInterface, Spi3WireInterface, SpiInterface};
+#[cfg(all(feature = "eh1", not(feature = "eh02")))]
+pub use interface::eh1::{I2cInterface, Spi3WireInterface, SpiInterface};
 pub use oled::Oled;
+#[cfg(feature = "async")]
+pub use oled_async::AsyncOled;
+pub use power::{ContrastFade, PixelShift};
+pub use scroll::{FrameInterval, Scroll, ScrollDirection};
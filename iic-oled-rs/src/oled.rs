@@ -1,35 +1,56 @@
-use crate::consts;
+use core::marker::PhantomData;
 
-use super::consts::SSD1306Cmd;
-use embedded_hal::blocking::i2c::Write;
+use crate::command::{self, Command, Commands, Transfer};
+use crate::config::Config;
+use crate::consts;
+use crate::controller::{AddressingModes, Controller, HardwareScroll, Ssd1306};
+use crate::framebuffer::FrameBuffer;
+use crate::interface::Interface;
+use crate::power::PixelShift;
+use crate::scroll::Scroll;
+use crate::Error;
 
-pub struct Oled<const M: usize, I: Write> {
-    i2c: I,
+pub struct Oled<const M: usize, DI: Interface, C: Controller = Ssd1306> {
+    interface: DI,
+    config: Config,
+    _controller: PhantomData<C>,
 }
 
-impl<const H: usize, I: Write> Oled<H, I> {
-    pub fn new(i2c: I) -> Self {
-        Oled { i2c }
+impl<const H: usize, DI: Interface, C: Controller> Oled<H, DI, C> {
+    pub fn new(interface: DI) -> Self {
+        Self::with_config(interface, Config::default())
+    }
+
+    /// `config` is applied by `init`.
+    pub fn with_config(interface: DI, config: Config) -> Self {
+        Oled {
+            interface,
+            config,
+            _controller: PhantomData,
+        }
+    }
+
+    /// Checks that a panel answers before `init`.
+    pub fn probe(&mut self) -> Result<(), Error<DI::Error>> {
+        self.interface.probe()
     }
 
-    pub fn write<const N: usize, const F: u8>(&mut self, data: &[u8]) -> Result<(), I::Error> {
-        let mut buffer = [0; N];
-        buffer[0] = F;
+    pub fn release(self) -> DI {
+        self.interface
+    }
 
-        data.chunks(N - 1).try_for_each(|c| {
-            let len = c.len();
-            buffer[1..=len].copy_from_slice(c);
-            self.i2c.write(consts::SSD1306_ADDR, &buffer)
-        })
+    /// Bus access for transfers the driver doesn't cover, like a DMA frame write.
+    pub fn interface_mut(&mut self) -> &mut DI {
+        &mut self.interface
     }
 
     #[inline(always)]
-    pub fn send_one_byte_cmd(&mut self, cmd: u8) -> Result<(), I::Error> {
-        self.i2c.write(consts::SSD1306_ADDR, &[0x80, cmd])
+    pub fn send_one_byte_cmd(&mut self, cmd: u8) -> Result<(), Error<DI::Error>> {
+        Ok(self.interface.send_commands::<2>(&[cmd])?)
     }
 
     #[inline(always)]
-    pub fn send_one_byte_cmds(&mut self, cmds: &[u8]) -> Result<(), I::Error> {
+    pub fn send_one_byte_cmds(&mut self, cmds: &[u8]) -> Result<(), Error<DI::Error>> {
         for ele in cmds {
             self.send_one_byte_cmd(*ele)?;
         }
@@ -37,61 +58,173 @@ impl<const H: usize, I: Write> Oled<H, I> {
     }
 
     #[inline(always)]
-    pub fn send_cmd(&mut self, cmd: &[u8]) -> Result<(), I::Error> {
-        self.write::<{ consts::CMD_BUFFER_SIZE + 1 }, 0x00>(cmd)
+    pub fn send_cmd(&mut self, cmd: &[u8]) -> Result<(), Error<DI::Error>> {
+        Ok(self
+            .interface
+            .send_commands::<{ consts::CMD_BUFFER_SIZE + 1 }>(cmd)?)
     }
 
     #[inline(always)]
-    pub fn send_data(&mut self, data: &[u8]) -> Result<(), I::Error> {
-        self.write::<{ consts::DATA_BUFFER_SIZE + 1 }, 0x40>(data)
+    pub fn send_data(&mut self, data: &[u8]) -> Result<(), Error<DI::Error>> {
+        Ok(self
+            .interface
+            .send_data::<{ consts::DATA_BUFFER_SIZE + 1 }>(data)?)
     }
 
     #[inline(always)]
-    pub fn send_data_custom<const N: usize>(&mut self, data: &[u8]) -> Result<(), I::Error> {
-        self.write::<N, 0x40>(data)
-    }
-
-    pub fn set_display_addr(&mut self, col: (u8, u8), page: (u8, u8)) -> Result<(), I::Error> {
-        self.send_cmd(&[SSD1306Cmd::SET_COLUMN_ADDR, col.0, col.1])?;
-        self.send_cmd(&[SSD1306Cmd::SET_PAGE_ADDR, page.0, page.1])
-    }
-
-    pub fn init(&mut self) -> Result<(), I::Error> {
-        self.send_one_byte_cmd(SSD1306Cmd::DISPLAY_OFF)?;
-        self.send_cmd(&[SSD1306Cmd::SET_DISPLAY_CLOCK_DIV, 0x80])?;
-        self.send_one_byte_cmd(SSD1306Cmd::SET_MULTIPLEX | (H as u8 - 1))?;
-        self.send_cmd(&[SSD1306Cmd::SET_DISPLAY_OFFSET, 0x00])?;
-        self.send_cmd(&[SSD1306Cmd::SET_CHARGE_PUMP, SSD1306Cmd::CHARGE_PUMP_ENABLE])?;
-        self.send_one_byte_cmds(&[
-            SSD1306Cmd::SET_DISPLAY_START_LINE,
-            SSD1306Cmd::SEG_REMAP | 0x1,
-            SSD1306Cmd::COM_SCAN_DEC,
-        ])?;
-        self.send_cmd(&[SSD1306Cmd::SET_COM_PINS, 0x12])?;
-        self.send_cmd(&[SSD1306Cmd::SET_CONTRAST, 0xCF])?;
-        self.send_cmd(&[SSD1306Cmd::SET_PRECHARGE, 0xF1])?;
-        self.send_cmd(&[SSD1306Cmd::SET_VCOM_DETECT, 0x40])?;
-        self.send_one_byte_cmds(&[
-            SSD1306Cmd::DISPLAY_ALL_ON_RESUME,
-            SSD1306Cmd::NORMAL_DISPLAY,
-            SSD1306Cmd::DISPLAY_ON,
-        ])
-    }
-
-    pub fn clear(&mut self) -> Result<(), I::Error> {
-        for _ in 0..H / 8 {
-            self.send_data(&[0x00; 128])?;
+    pub fn send_data_custom<const N: usize>(
+        &mut self,
+        data: &[u8],
+    ) -> Result<(), Error<DI::Error>> {
+        Ok(self.interface.send_data::<N>(data)?)
+    }
+
+    pub fn set_display_addr(
+        &mut self,
+        col: (u8, u8),
+        page: (u8, u8),
+    ) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::display_addr::<_, H>(col, page)?)
+    }
+
+    /// Sets the write position in page addressing mode, `col` being the RAM column.
+    pub fn set_page_column(&mut self, page: u8, col: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::page_column::<_, H, C>(page, col)?)
+    }
+
+    pub fn init(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::init::<_, H, C>(&self.config)?)
+    }
+
+    pub fn clear(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::clear::<H, C>())
+    }
+
+    /// Turns the panel off, RAM is kept and still writable.
+    pub fn sleep(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::sleep::<C>(&self.config))
+    }
+
+    pub fn wake(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::wake::<C>(&self.config))
+    }
+
+    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::contrast(contrast))?;
+        self.config.contrast = contrast;
+        Ok(())
+    }
+
+    /// Steps the contrast to `target`, `step` at a time, calling `wait` between steps.
+    pub fn fade_contrast(
+        &mut self,
+        target: u8,
+        step: u8,
+        mut wait: impl FnMut(),
+    ) -> Result<(), Error<DI::Error>> {
+        for (wait_first, contrast) in command::fade_contrast(self.config.contrast, target, step) {
+            if wait_first {
+                wait();
+            }
+            self.set_contrast(contrast)?;
         }
         Ok(())
     }
 
+    /// Maps COM row 0 to `rows`, shifting the picture up.
+    pub fn set_display_offset(&mut self, rows: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::display_offset::<_, H>(rows)?)
+    }
+
+    /// Shows RAM row `line` on the top row, shifting the picture up.
+    pub fn set_start_line(&mut self, line: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::start_line::<_, H>(line)?)
+    }
+
+    /// Counts a tick of `shift`, moving the picture when it is due.
+    ///
+    /// Fails with `Error::OutOfRange` if `shift` moves the picture by the panel height or more.
+    pub fn pixel_shift(&mut self, shift: &mut PixelShift) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::pixel_shift::<_, H>(shift)?)
+    }
+
+    /// Writes a whole frame laid out page by page, `DISPLAY_WIDTH` bytes per page.
+    pub fn draw(&mut self, frame: &[u8]) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::draw::<_, H, C>(frame)?)
+    }
+
+    #[inline(always)]
+    pub fn flush<const P: usize>(&mut self, fb: &FrameBuffer<P>) -> Result<(), Error<DI::Error>> {
+        self.draw(fb.as_bytes())
+    }
+
+    /// Writes a whole frame laid out column by column, `H / 8` bytes per column.
+    pub fn draw_vertical(&mut self, frame: &[u8]) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::draw_vertical::<_, H, C>(frame)?)
+    }
+
+    fn send_cmds(&mut self, cmds: &Commands) -> Result<(), Error<DI::Error>> {
+        for cmd in cmds {
+            self.send_command(cmd)?;
+        }
+        Ok(())
+    }
+
+    fn send_command(&mut self, cmd: &Command) -> Result<(), Error<DI::Error>> {
+        match cmd.len() {
+            1 => self.send_one_byte_cmd(cmd[0]),
+            _ => self.send_cmd(cmd),
+        }
+    }
+
+    fn send_transfers<'a>(
+        &mut self,
+        transfers: impl Iterator<Item = Transfer<'a>>,
+    ) -> Result<(), Error<DI::Error>> {
+        for transfer in transfers {
+            match transfer {
+                Transfer::Cmd(cmd) => self.send_command(&cmd)?,
+                Transfer::Data(data) => self.send_data(data)?,
+                Transfer::Page(page) => self.send_data(&page)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const H: usize, DI: Interface, C: AddressingModes> Oled<H, DI, C> {
     #[inline(always)]
-    pub fn vertical_mem_mode(&mut self) -> Result<(), I::Error> {
-        self.send_cmd(&[SSD1306Cmd::MEMORY_MODE, 0x01])
+    pub fn vertical_mem_mode(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::memory_mode(0x01))
     }
 
     #[inline(always)]
-    pub fn horizontal_mem_mode(&mut self) -> Result<(), I::Error> {
-        self.send_cmd(&[SSD1306Cmd::MEMORY_MODE, 0x00])
+    pub fn horizontal_mem_mode(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::memory_mode(0x00))
+    }
+}
+
+impl<const H: usize, DI: Interface, C: HardwareScroll> Oled<H, DI, C> {
+    /// Stops any running scroll, sets up `scroll` and starts it.
+    ///
+    /// RAM written while scrolling may be corrupted, call `stop_scroll` before drawing.
+    pub fn start_scroll(&mut self, scroll: &Scroll) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::start_scroll::<_, H, C>(scroll)?)
+    }
+
+    pub fn stop_scroll(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::stop_scroll())
+    }
+
+    /// Rows `fixed_rows..fixed_rows + scroll_rows` move in a diagonal scroll, the top ones stay.
+    pub fn set_vertical_scroll_area(
+        &mut self,
+        fixed_rows: u8,
+        scroll_rows: u8,
+    ) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::vertical_scroll_area::<_, H>(
+            fixed_rows,
+            scroll_rows,
+        )?)
     }
 }
@@ -0,0 +1,230 @@
+use core::marker::PhantomData;
+
+use embedded_hal_async::delay::DelayNs;
+
+use crate::command::{self, Command, Commands, Transfer};
+use crate::config::Config;
+use crate::consts;
+use crate::controller::{AddressingModes, Controller, HardwareScroll, Ssd1306};
+use crate::framebuffer::FrameBuffer;
+use crate::interface::AsyncInterface;
+use crate::power::PixelShift;
+use crate::scroll::Scroll;
+use crate::Error;
+
+/// Async variant of [`Oled`](crate::Oled), sending the same command sequences.
+pub struct AsyncOled<const M: usize, DI: AsyncInterface, C: Controller = Ssd1306> {
+    interface: DI,
+    config: Config,
+    _controller: PhantomData<C>,
+}
+
+impl<const H: usize, DI: AsyncInterface, C: Controller> AsyncOled<H, DI, C> {
+    pub fn new(interface: DI) -> Self {
+        Self::with_config(interface, Config::default())
+    }
+
+    /// `config` is applied by `init`.
+    pub fn with_config(interface: DI, config: Config) -> Self {
+        AsyncOled {
+            interface,
+            config,
+            _controller: PhantomData,
+        }
+    }
+
+    /// Checks that a panel answers before `init`.
+    pub async fn probe(&mut self) -> Result<(), Error<DI::Error>> {
+        self.interface.probe().await
+    }
+
+    pub fn release(self) -> DI {
+        self.interface
+    }
+
+    /// Bus access for transfers the driver doesn't cover, like a DMA frame write.
+    pub fn interface_mut(&mut self) -> &mut DI {
+        &mut self.interface
+    }
+
+    #[inline(always)]
+    pub async fn send_one_byte_cmd(&mut self, cmd: u8) -> Result<(), Error<DI::Error>> {
+        Ok(self.interface.send_commands::<2>(&[cmd]).await?)
+    }
+
+    #[inline(always)]
+    pub async fn send_cmd(&mut self, cmd: &[u8]) -> Result<(), Error<DI::Error>> {
+        Ok(self
+            .interface
+            .send_commands::<{ consts::CMD_BUFFER_SIZE + 1 }>(cmd)
+            .await?)
+    }
+
+    #[inline(always)]
+    pub async fn send_data(&mut self, data: &[u8]) -> Result<(), Error<DI::Error>> {
+        Ok(self
+            .interface
+            .send_data::<{ consts::DATA_BUFFER_SIZE + 1 }>(data)
+            .await?)
+    }
+
+    pub async fn set_display_addr(
+        &mut self,
+        col: (u8, u8),
+        page: (u8, u8),
+    ) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::display_addr::<_, H>(col, page)?)
+            .await
+    }
+
+    /// Sets the write position in page addressing mode, `col` being the RAM column.
+    pub async fn set_page_column(&mut self, page: u8, col: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::page_column::<_, H, C>(page, col)?)
+            .await
+    }
+
+    pub async fn init(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::init::<_, H, C>(&self.config)?)
+            .await
+    }
+
+    pub async fn clear(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::clear::<H, C>()).await
+    }
+
+    /// Turns the panel off, RAM is kept and still writable.
+    pub async fn sleep(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::sleep::<C>(&self.config)).await
+    }
+
+    pub async fn wake(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::wake::<C>(&self.config)).await
+    }
+
+    pub async fn set_contrast(&mut self, contrast: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::contrast(contrast)).await?;
+        self.config.contrast = contrast;
+        Ok(())
+    }
+
+    /// Steps the contrast to `target`, `step` at a time, `step_ms` apart.
+    pub async fn fade_contrast<D: DelayNs>(
+        &mut self,
+        target: u8,
+        step: u8,
+        delay: &mut D,
+        step_ms: u32,
+    ) -> Result<(), Error<DI::Error>> {
+        for (wait_first, contrast) in command::fade_contrast(self.config.contrast, target, step) {
+            if wait_first {
+                delay.delay_ms(step_ms).await;
+            }
+            self.set_contrast(contrast).await?;
+        }
+        Ok(())
+    }
+
+    /// Maps COM row 0 to `rows`, shifting the picture up.
+    pub async fn set_display_offset(&mut self, rows: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::display_offset::<_, H>(rows)?)
+            .await
+    }
+
+    /// Shows RAM row `line` on the top row, shifting the picture up.
+    pub async fn set_start_line(&mut self, line: u8) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::start_line::<_, H>(line)?).await
+    }
+
+    /// Counts a tick of `shift`, moving the picture when it is due.
+    ///
+    /// Fails with `Error::OutOfRange` if `shift` moves the picture by the panel height or more.
+    pub async fn pixel_shift(&mut self, shift: &mut PixelShift) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::pixel_shift::<_, H>(shift)?).await
+    }
+
+    /// Writes a whole frame laid out page by page, `DISPLAY_WIDTH` bytes per page.
+    pub async fn draw(&mut self, frame: &[u8]) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::draw::<_, H, C>(frame)?).await
+    }
+
+    #[inline(always)]
+    pub async fn flush<const P: usize>(
+        &mut self,
+        fb: &FrameBuffer<P>,
+    ) -> Result<(), Error<DI::Error>> {
+        self.draw(fb.as_bytes()).await
+    }
+
+    /// Writes a whole frame laid out column by column, `H / 8` bytes per column.
+    pub async fn draw_vertical(&mut self, frame: &[u8]) -> Result<(), Error<DI::Error>> {
+        self.send_transfers(command::draw_vertical::<_, H, C>(frame)?)
+            .await
+    }
+
+    async fn send_cmds(&mut self, cmds: &Commands) -> Result<(), Error<DI::Error>> {
+        for cmd in cmds {
+            self.send_command(cmd).await?;
+        }
+        Ok(())
+    }
+
+    async fn send_command(&mut self, cmd: &Command) -> Result<(), Error<DI::Error>> {
+        match cmd.len() {
+            1 => self.send_one_byte_cmd(cmd[0]).await,
+            _ => self.send_cmd(cmd).await,
+        }
+    }
+
+    async fn send_transfers<'a>(
+        &mut self,
+        transfers: impl Iterator<Item = Transfer<'a>>,
+    ) -> Result<(), Error<DI::Error>> {
+        for transfer in transfers {
+            match transfer {
+                Transfer::Cmd(cmd) => self.send_command(&cmd).await?,
+                Transfer::Data(data) => self.send_data(data).await?,
+                Transfer::Page(page) => self.send_data(&page).await?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const H: usize, DI: AsyncInterface, C: AddressingModes> AsyncOled<H, DI, C> {
+    #[inline(always)]
+    pub async fn vertical_mem_mode(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::memory_mode(0x01)).await
+    }
+
+    #[inline(always)]
+    pub async fn horizontal_mem_mode(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::memory_mode(0x00)).await
+    }
+}
+
+impl<const H: usize, DI: AsyncInterface, C: HardwareScroll> AsyncOled<H, DI, C> {
+    /// Stops any running scroll, sets up `scroll` and starts it.
+    ///
+    /// RAM written while scrolling may be corrupted, call `stop_scroll` before drawing.
+    pub async fn start_scroll(&mut self, scroll: &Scroll) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::start_scroll::<_, H, C>(scroll)?)
+            .await
+    }
+
+    pub async fn stop_scroll(&mut self) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::stop_scroll()).await
+    }
+
+    /// Rows `fixed_rows..fixed_rows + scroll_rows` move in a diagonal scroll, the top ones stay.
+    pub async fn set_vertical_scroll_area(
+        &mut self,
+        fixed_rows: u8,
+        scroll_rows: u8,
+    ) -> Result<(), Error<DI::Error>> {
+        self.send_cmds(&command::vertical_scroll_area::<_, H>(
+            fixed_rows,
+            scroll_rows,
+        )?)
+        .await
+    }
+}
@@ -0,0 +1,97 @@
+//! Helpers against burn-in of static content.
+
+/// Contrast values from one level to another, `step` apart, ending on the target.
+///
+/// Sent by `Oled::fade_contrast`, or one per tick with `Oled::set_contrast`.
+#[derive(Debug, Clone)]
+pub struct ContrastFade {
+    current: Option<u8>,
+    target: u8,
+    step: u8,
+}
+
+impl ContrastFade {
+    /// The first value is one `step` away from `from`.
+    pub fn new(from: u8, to: u8, step: u8) -> Self {
+        let mut fade = ContrastFade {
+            current: Some(from),
+            target: to,
+            step: step.max(1),
+        };
+        if from == to {
+            fade.current = None;
+        }
+        fade
+    }
+}
+
+impl Iterator for ContrastFade {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let current = self.current?;
+        let next = if current < self.target {
+            current.saturating_add(self.step).min(self.target)
+        } else {
+            current.saturating_sub(self.step).max(self.target)
+        };
+        self.current = (next != self.target).then_some(next);
+        Some(next)
+    }
+}
+
+/// Moves the picture up and back down a row at a time, every `period` ticks.
+///
+/// Applied with `Oled::pixel_shift` through the display start line, so rows
+/// pushed off the top come back at the bottom: keep `max_rows` of blank
+/// margin in the picture. `max_rows` must be less than the panel height.
+#[derive(Debug, Clone)]
+pub struct PixelShift {
+    max_rows: u8,
+    period: u32,
+    ticks: u32,
+    line: u8,
+    rising: bool,
+}
+
+impl PixelShift {
+    pub fn new(max_rows: u8, period: u32) -> Self {
+        PixelShift {
+            max_rows,
+            period: period.max(1),
+            ticks: 0,
+            line: 0,
+            rising: true,
+        }
+    }
+
+    pub fn max_rows(&self) -> u8 {
+        self.max_rows
+    }
+
+    /// Start line currently applied.
+    pub fn line(&self) -> u8 {
+        self.line
+    }
+
+    /// Counts one tick, returns the new start line when it is time to move.
+    pub fn tick(&mut self) -> Option<u8> {
+        self.ticks += 1;
+        if self.ticks < self.period || self.max_rows == 0 {
+            return None;
+        }
+        self.ticks = 0;
+
+        if self.line == self.max_rows {
+            self.rising = false;
+        } else if self.line == 0 {
+            self.rising = true;
+        }
+        self.line = if self.rising {
+            self.line + 1
+        } else {
+            self.line - 1
+        };
+        Some(self.line)
+    }
+}
@@ -0,0 +1,75 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollDirection {
+    Right,
+    Left,
+}
+
+/// Frames between two scroll steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameInterval {
+    Frames2,
+    Frames3,
+    Frames4,
+    Frames5,
+    Frames25,
+    Frames64,
+    Frames128,
+    Frames256,
+}
+
+impl FrameInterval {
+    pub(crate) fn bits(self) -> u8 {
+        match self {
+            FrameInterval::Frames5 => 0b000,
+            FrameInterval::Frames64 => 0b001,
+            FrameInterval::Frames128 => 0b010,
+            FrameInterval::Frames256 => 0b011,
+            FrameInterval::Frames3 => 0b100,
+            FrameInterval::Frames4 => 0b101,
+            FrameInterval::Frames25 => 0b110,
+            FrameInterval::Frames2 => 0b111,
+        }
+    }
+}
+
+/// Continuous scroll setup, started with `Oled::start_scroll`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scroll {
+    pub direction: ScrollDirection,
+    /// First and last page that scroll horizontally.
+    pub pages: (u8, u8),
+    pub interval: FrameInterval,
+    /// Rows moved up per step, 0 scrolls horizontally only.
+    pub vertical_offset: u8,
+}
+
+impl Scroll {
+    pub fn horizontal(
+        direction: ScrollDirection,
+        pages: (u8, u8),
+        interval: FrameInterval,
+    ) -> Self {
+        Scroll {
+            direction,
+            pages,
+            interval,
+            vertical_offset: 0,
+        }
+    }
+
+    /// Horizontal scroll combined with a vertical one, limited to the area
+    /// set by `Oled::set_vertical_scroll_area`.
+    pub fn diagonal(
+        direction: ScrollDirection,
+        pages: (u8, u8),
+        interval: FrameInterval,
+        vertical_offset: u8,
+    ) -> Self {
+        Scroll {
+            direction,
+            pages,
+            interval,
+            vertical_offset,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use core::fmt;
+
+use super::Font;
+use crate::framebuffer::FrameBuffer;
+
+/// Terminal on a [`FrameBuffer`], written with `write!` and `writeln!`.
+///
+/// Text wraps at the right edge and the content moves up a line once the
+/// bottom is reached. Send the frame with `Oled::flush` after writing.
+pub struct Console<const P: usize> {
+    frame: FrameBuffer<P>,
+    font: Font,
+    cursor: (usize, usize),
+}
+
+impl<const P: usize> Console<P> {
+    pub fn new(frame: FrameBuffer<P>, font: Font) -> Self {
+        Console {
+            frame,
+            font,
+            cursor: (0, 0),
+        }
+    }
+
+    pub fn frame(&self) -> &FrameBuffer<P> {
+        &self.frame
+    }
+
+    pub fn into_inner(self) -> FrameBuffer<P> {
+        self.frame
+    }
+
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+    }
+
+    /// Clears the frame and moves the cursor to the top left.
+    pub fn clear(&mut self) {
+        self.frame.clear();
+        self.cursor = (0, 0);
+    }
+
+    fn put(&mut self, c: char) {
+        let (width, height) = self.frame.size();
+        let line = self.font.line_height();
+        let (mut x, mut y) = self.cursor;
+        match c {
+            '\n' => {
+                self.cursor = (0, y + line);
+                return;
+            }
+            '\r' => {
+                self.cursor = (0, y);
+                return;
+            }
+            _ => {}
+        }
+
+        if x > 0 && x + self.font.advance(c) > width {
+            (x, y) = (0, y + line);
+        }
+        // scroll only when a character needs the space, a trailing newline keeps the last line,
+        // lines taller than the frame stay at the top and are clipped
+        if y + line > height {
+            let rows = (y + line - height).min(y);
+            self.frame.shift_up(rows);
+            y -= rows;
+        }
+        self.frame.draw_char(&self.font, (x, y), c);
+        self.cursor = (x + self.font.advance(c), y);
+    }
+}
+
+impl<const P: usize> fmt::Write for Console<P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().for_each(|c| self.put(c));
+        Ok(())
+    }
+}
@@ -0,0 +1,318 @@
+//! Glyph tables, columns left to right with the least significant bit on top.
+
+/// ASCII `' '..='~'`, 5 columns of 7 rows each.
+pub(super) const ASCII_5X7: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5F, 0x00, 0x00], // '!'
+    [0x00, 0x07, 0x00, 0x07, 0x00], // '"'
+    [0x14, 0x7F, 0x14, 0x7F, 0x14], // '#'
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], // '$'
+    [0x23, 0x13, 0x08, 0x64, 0x62], // '%'
+    [0x36, 0x49, 0x56, 0x20, 0x50], // '&'
+    [0x00, 0x05, 0x03, 0x00, 0x00], // "'"
+    [0x00, 0x1C, 0x22, 0x41, 0x00], // '('
+    [0x00, 0x41, 0x22, 0x1C, 0x00], // ')'
+    [0x2A, 0x1C, 0x7F, 0x1C, 0x2A], // '*'
+    [0x08, 0x08, 0x3E, 0x08, 0x08], // '+'
+    [0x00, 0x50, 0x30, 0x00, 0x00], // ','
+    [0x08, 0x08, 0x08, 0x08, 0x08], // '-'
+    [0x00, 0x60, 0x60, 0x00, 0x00], // '.'
+    [0x20, 0x10, 0x08, 0x04, 0x02], // '/'
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], // '0'
+    [0x00, 0x42, 0x7F, 0x40, 0x00], // '1'
+    [0x42, 0x61, 0x51, 0x49, 0x46], // '2'
+    [0x21, 0x41, 0x45, 0x4B, 0x31], // '3'
+    [0x18, 0x14, 0x12, 0x7F, 0x10], // '4'
+    [0x27, 0x45, 0x45, 0x45, 0x39], // '5'
+    [0x3C, 0x4A, 0x49, 0x49, 0x30], // '6'
+    [0x01, 0x71, 0x09, 0x05, 0x03], // '7'
+    [0x36, 0x49, 0x49, 0x49, 0x36], // '8'
+    [0x06, 0x49, 0x49, 0x29, 0x1E], // '9'
+    [0x00, 0x36, 0x36, 0x00, 0x00], // ':'
+    [0x00, 0x56, 0x36, 0x00, 0x00], // ';'
+    [0x08, 0x14, 0x22, 0x41, 0x00], // '<'
+    [0x14, 0x14, 0x14, 0x14, 0x14], // '='
+    [0x00, 0x41, 0x22, 0x14, 0x08], // '>'
+    [0x02, 0x01, 0x51, 0x09, 0x06], // '?'
+    [0x32, 0x49, 0x79, 0x41, 0x3E], // '@'
+    [0x7E, 0x11, 0x11, 0x11, 0x7E], // 'A'
+    [0x7F, 0x49, 0x49, 0x49, 0x36], // 'B'
+    [0x3E, 0x41, 0x41, 0x41, 0x22], // 'C'
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], // 'D'
+    [0x7F, 0x49, 0x49, 0x49, 0x41], // 'E'
+    [0x7F, 0x09, 0x09, 0x09, 0x01], // 'F'
+    [0x3E, 0x41, 0x49, 0x49, 0x7A], // 'G'
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], // 'H'
+    [0x00, 0x41, 0x7F, 0x41, 0x00], // 'I'
+    [0x20, 0x40, 0x41, 0x3F, 0x01], // 'J'
+    [0x7F, 0x08, 0x14, 0x22, 0x41], // 'K'
+    [0x7F, 0x40, 0x40, 0x40, 0x40], // 'L'
+    [0x7F, 0x02, 0x0C, 0x02, 0x7F], // 'M'
+    [0x7F, 0x04, 0x08, 0x10, 0x7F], // 'N'
+    [0x3E, 0x41, 0x41, 0x41, 0x3E], // 'O'
+    [0x7F, 0x09, 0x09, 0x09, 0x06], // 'P'
+    [0x3E, 0x41, 0x51, 0x21, 0x5E], // 'Q'
+    [0x7F, 0x09, 0x19, 0x29, 0x46], // 'R'
+    [0x46, 0x49, 0x49, 0x49, 0x31], // 'S'
+    [0x01, 0x01, 0x7F, 0x01, 0x01], // 'T'
+    [0x3F, 0x40, 0x40, 0x40, 0x3F], // 'U'
+    [0x1F, 0x20, 0x40, 0x20, 0x1F], // 'V'
+    [0x3F, 0x40, 0x38, 0x40, 0x3F], // 'W'
+    [0x63, 0x14, 0x08, 0x14, 0x63], // 'X'
+    [0x07, 0x08, 0x70, 0x08, 0x07], // 'Y'
+    [0x61, 0x51, 0x49, 0x45, 0x43], // 'Z'
+    [0x00, 0x7F, 0x41, 0x41, 0x00], // '['
+    [0x02, 0x04, 0x08, 0x10, 0x20], // '\\'
+    [0x00, 0x41, 0x41, 0x7F, 0x00], // ']'
+    [0x04, 0x02, 0x01, 0x02, 0x04], // '^'
+    [0x40, 0x40, 0x40, 0x40, 0x40], // '_'
+    [0x00, 0x01, 0x02, 0x04, 0x00], // '`'
+    [0x20, 0x54, 0x54, 0x54, 0x78], // 'a'
+    [0x7F, 0x48, 0x44, 0x44, 0x38], // 'b'
+    [0x38, 0x44, 0x44, 0x44, 0x20], // 'c'
+    [0x38, 0x44, 0x44, 0x48, 0x7F], // 'd'
+    [0x38, 0x54, 0x54, 0x54, 0x18], // 'e'
+    [0x08, 0x7E, 0x09, 0x01, 0x02], // 'f'
+    [0x0C, 0x52, 0x52, 0x52, 0x3E], // 'g'
+    [0x7F, 0x08, 0x04, 0x04, 0x78], // 'h'
+    [0x00, 0x44, 0x7D, 0x40, 0x00], // 'i'
+    [0x20, 0x40, 0x44, 0x3D, 0x00], // 'j'
+    [0x7F, 0x10, 0x28, 0x44, 0x00], // 'k'
+    [0x00, 0x41, 0x7F, 0x40, 0x00], // 'l'
+    [0x7C, 0x04, 0x18, 0x04, 0x78], // 'm'
+    [0x7C, 0x08, 0x04, 0x04, 0x78], // 'n'
+    [0x38, 0x44, 0x44, 0x44, 0x38], // 'o'
+    [0x7C, 0x14, 0x14, 0x14, 0x08], // 'p'
+    [0x08, 0x14, 0x14, 0x18, 0x7C], // 'q'
+    [0x7C, 0x08, 0x04, 0x04, 0x08], // 'r'
+    [0x48, 0x54, 0x54, 0x54, 0x20], // 's'
+    [0x04, 0x3F, 0x44, 0x40, 0x20], // 't'
+    [0x3C, 0x40, 0x40, 0x20, 0x7C], // 'u'
+    [0x1C, 0x20, 0x40, 0x20, 0x1C], // 'v'
+    [0x3C, 0x40, 0x30, 0x40, 0x3C], // 'w'
+    [0x44, 0x28, 0x10, 0x28, 0x44], // 'x'
+    [0x0C, 0x50, 0x50, 0x50, 0x3C], // 'y'
+    [0x44, 0x64, 0x54, 0x4C, 0x44], // 'z'
+    [0x00, 0x08, 0x36, 0x41, 0x00], // '{'
+    [0x00, 0x00, 0x7F, 0x00, 0x00], // '|'
+    [0x00, 0x41, 0x36, 0x08, 0x00], // '}'
+    [0x08, 0x04, 0x08, 0x10, 0x08], // '~'
+];
+
+/// ASCII `' '..='~'`, 8 columns of 16 rows each.
+#[rustfmt::skip]
+const COLUMNS_8X16: [[u16; 8]; 95] = [
+    [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000], // ' '
+    [0x0000, 0x0000, 0x0038, 0x0DFC, 0x0DFC, 0x0038, 0x0000, 0x0000], // '!'
+    [0x0000, 0x001C, 0x003C, 0x0000, 0x0000, 0x003C, 0x001C, 0x0000], // '"'
+    [0x0120, 0x07F8, 0x07F8, 0x0120, 0x07F8, 0x07F8, 0x0120, 0x0000], // '#'
+    [0x0CE0, 0x19F0, 0x1110, 0x711C, 0x711C, 0x1F30, 0x0E60, 0x0000], // '$'
+    [0x0C30, 0x0630, 0x0300, 0x0180, 0x00C0, 0x0C60, 0x0C30, 0x0000], // '%'
+    [0x0780, 0x0FD8, 0x087C, 0x08E4, 0x07BC, 0x0FD8, 0x0840, 0x0000], // '&'
+    [0x0000, 0x0020, 0x003C, 0x001C, 0x0000, 0x0000, 0x0000, 0x0000], // "'"
+    [0x0000, 0x0000, 0x03F0, 0x07F8, 0x0C0C, 0x0804, 0x0000, 0x0000], // '('
+    [0x0000, 0x0000, 0x0804, 0x0C0C, 0x07F8, 0x03F0, 0x0000, 0x0000], // ')'
+    [0x0080, 0x02A0, 0x01C0, 0x0080, 0x01C0, 0x02A0, 0x0080, 0x0000], // '*'
+    [0x0000, 0x0080, 0x0080, 0x03E0, 0x03E0, 0x0080, 0x0080, 0x0000], // '+'
+    [0x0000, 0x0000, 0x2000, 0x3C00, 0x1C00, 0x0000, 0x0000, 0x0000], // ','
+    [0x0080, 0x0080, 0x0080, 0x0080, 0x0080, 0x0080, 0x0080, 0x0000], // '-'
+    [0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000], // '.'
+    [0x0C00, 0x0600, 0x0300, 0x0180, 0x00C0, 0x0060, 0x0030, 0x0000], // '/'
+    [0x03F0, 0x07F8, 0x0C0C, 0x08C4, 0x0C0C, 0x07F8, 0x03F0, 0x0000], // '0'
+    [0x0000, 0x0810, 0x0818, 0x0FFC, 0x0FFC, 0x0800, 0x0800, 0x0000], // '1'
+    [0x0E08, 0x0F0C, 0x0984, 0x08C4, 0x0864, 0x0C3C, 0x0C18, 0x0000], // '2'
+    [0x0408, 0x0C0C, 0x0844, 0x0844, 0x0844, 0x0FFC, 0x07B8, 0x0000], // '3'
+    [0x00C0, 0x00E0, 0x00B0, 0x0898, 0x0FFC, 0x0FFC, 0x0880, 0x0000], // '4'
+    [0x047C, 0x0C7C, 0x0844, 0x0844, 0x0844, 0x0FC4, 0x0784, 0x0000], // '5'
+    [0x07F0, 0x0FF8, 0x084C, 0x0844, 0x0844, 0x0FC0, 0x0780, 0x0000], // '6'
+    [0x000C, 0x000C, 0x0F84, 0x0FC4, 0x0064, 0x003C, 0x001C, 0x0000], // '7'
+    [0x07B8, 0x0FFC, 0x0844, 0x0844, 0x0844, 0x0FFC, 0x07B8, 0x0000], // '8'
+    [0x0038, 0x087C, 0x0844, 0x0844, 0x0C44, 0x07FC, 0x03F8, 0x0000], // '9'
+    [0x0000, 0x0000, 0x0000, 0x0630, 0x0630, 0x0000, 0x0000, 0x0000], // ':'
+    [0x0000, 0x0000, 0x0800, 0x0E30, 0x0630, 0x0000, 0x0000, 0x0000], // ';'
+    [0x0000, 0x0080, 0x01C0, 0x0360, 0x0630, 0x0C18, 0x0808, 0x0000], // '<'
+    [0x0000, 0x0120, 0x0120, 0x0120, 0x0120, 0x0120, 0x0120, 0x0000], // '='
+    [0x0000, 0x0808, 0x0C18, 0x0630, 0x0360, 0x01C0, 0x0080, 0x0000], // '>'
+    [0x0018, 0x001C, 0x0004, 0x0DC4, 0x0DE4, 0x003C, 0x0018, 0x0000], // '?'
+    [0x07F0, 0x0FF8, 0x0808, 0x0BC8, 0x0BC8, 0x0BF8, 0x01F0, 0x0000], // '@'
+    [0x0FE0, 0x0FF0, 0x0098, 0x008C, 0x0098, 0x0FF0, 0x0FE0, 0x0000], // 'A'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0844, 0x0844, 0x0FFC, 0x07B8, 0x0000], // 'B'
+    [0x03F0, 0x07F8, 0x0C0C, 0x0804, 0x0804, 0x0C0C, 0x0618, 0x0000], // 'C'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0804, 0x0C0C, 0x07F8, 0x03F0, 0x0000], // 'D'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0844, 0x08E4, 0x0C0C, 0x0E1C, 0x0000], // 'E'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0844, 0x00E4, 0x000C, 0x001C, 0x0000], // 'F'
+    [0x03F0, 0x07F8, 0x0C0C, 0x0884, 0x0884, 0x078C, 0x0F98, 0x0000], // 'G'
+    [0x0FFC, 0x0FFC, 0x0040, 0x0040, 0x0040, 0x0FFC, 0x0FFC, 0x0000], // 'H'
+    [0x0000, 0x0000, 0x0804, 0x0FFC, 0x0FFC, 0x0804, 0x0000, 0x0000], // 'I'
+    [0x0700, 0x0F00, 0x0800, 0x0804, 0x0FFC, 0x07FC, 0x0004, 0x0000], // 'J'
+    [0x0804, 0x0FFC, 0x0FFC, 0x00C0, 0x01F0, 0x0F3C, 0x0E0C, 0x0000], // 'K'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0804, 0x0800, 0x0C00, 0x0E00, 0x0000], // 'L'
+    [0x0FFC, 0x0FFC, 0x0038, 0x0070, 0x0038, 0x0FFC, 0x0FFC, 0x0000], // 'M'
+    [0x0FFC, 0x0FFC, 0x0038, 0x0070, 0x00E0, 0x0FFC, 0x0FFC, 0x0000], // 'N'
+    [0x07F8, 0x0FFC, 0x0804, 0x0804, 0x0804, 0x0FFC, 0x07F8, 0x0000], // 'O'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0844, 0x0044, 0x007C, 0x0038, 0x0000], // 'P'
+    [0x07F8, 0x0FFC, 0x0804, 0x0E04, 0x3C04, 0x3FFC, 0x27F8, 0x0000], // 'Q'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0044, 0x00C4, 0x0FFC, 0x0F38, 0x0000], // 'R'
+    [0x0618, 0x0E3C, 0x0864, 0x0844, 0x08C4, 0x0F9C, 0x0718, 0x0000], // 'S'
+    [0x0000, 0x000C, 0x0804, 0x0FFC, 0x0FFC, 0x0804, 0x000C, 0x0000], // 'T'
+    [0x07FC, 0x0FFC, 0x0800, 0x0800, 0x0800, 0x0FFC, 0x07FC, 0x0000], // 'U'
+    [0x01FC, 0x03FC, 0x0600, 0x0C00, 0x0600, 0x03FC, 0x01FC, 0x0000], // 'V'
+    [0x07FC, 0x0FFC, 0x0E00, 0x03C0, 0x0E00, 0x0FFC, 0x07FC, 0x0000], // 'W'
+    [0x0C0C, 0x0F3C, 0x03F0, 0x01E0, 0x03F0, 0x0F3C, 0x0C0C, 0x0000], // 'X'
+    [0x0000, 0x007C, 0x08FC, 0x0F80, 0x0F80, 0x08FC, 0x007C, 0x0000], // 'Y'
+    [0x0E1C, 0x0F0C, 0x0984, 0x08C4, 0x0864, 0x0C3C, 0x0E1C, 0x0000], // 'Z'
+    [0x0000, 0x0000, 0x0FFC, 0x0FFC, 0x0804, 0x0804, 0x0000, 0x0000], // '['
+    [0x0030, 0x0060, 0x00C0, 0x0180, 0x0300, 0x0600, 0x0C00, 0x0000], // '\\'
+    [0x0000, 0x0000, 0x0804, 0x0804, 0x0FFC, 0x0FFC, 0x0000, 0x0000], // ']'
+    [0x0020, 0x0030, 0x0018, 0x000C, 0x0018, 0x0030, 0x0020, 0x0000], // '^'
+    [0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000], // '_'
+    [0x0000, 0x0000, 0x0004, 0x000C, 0x0018, 0x0010, 0x0000, 0x0000], // '`'
+    [0x0700, 0x0FA0, 0x08A0, 0x08A0, 0x07E0, 0x0FC0, 0x0800, 0x0000], // 'a'
+    [0x0004, 0x0FFC, 0x0FFC, 0x0820, 0x0860, 0x0FC0, 0x0780, 0x0000], // 'b'
+    [0x07C0, 0x0FE0, 0x0820, 0x0820, 0x0820, 0x0C60, 0x0440, 0x0000], // 'c'
+    [0x0780, 0x0FC0, 0x0860, 0x0824, 0x07FC, 0x0FFC, 0x0800, 0x0000], // 'd'
+    [0x07C0, 0x0FE0, 0x08A0, 0x08A0, 0x08A0, 0x0CE0, 0x04C0, 0x0000], // 'e'
+    [0x0840, 0x0FF8, 0x0FFC, 0x0844, 0x000C, 0x0018, 0x0000, 0x0000], // 'f'
+    [0x27C0, 0x6FE0, 0x4820, 0x4820, 0x7FC0, 0x3FE0, 0x0020, 0x0000], // 'g'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0040, 0x0020, 0x0FE0, 0x0FC0, 0x0000], // 'h'
+    [0x0000, 0x0000, 0x0820, 0x0FEC, 0x0FEC, 0x0800, 0x0000, 0x0000], // 'i'
+    [0x0000, 0x3000, 0x7000, 0x4000, 0x4020, 0x7FEC, 0x3FEC, 0x0000], // 'j'
+    [0x0804, 0x0FFC, 0x0FFC, 0x0180, 0x03C0, 0x0E60, 0x0C20, 0x0000], // 'k'
+    [0x0000, 0x0000, 0x0804, 0x0FFC, 0x0FFC, 0x0800, 0x0000, 0x0000], // 'l'
+    [0x0FE0, 0x0FE0, 0x0060, 0x07C0, 0x0060, 0x0FE0, 0x0FC0, 0x0000], // 'm'
+    [0x0020, 0x0FE0, 0x0FC0, 0x0020, 0x0020, 0x0FE0, 0x0FC0, 0x0000], // 'n'
+    [0x07C0, 0x0FE0, 0x0820, 0x0820, 0x0820, 0x0FE0, 0x07C0, 0x0000], // 'o'
+    [0x4020, 0x7FE0, 0x7FC0, 0x4820, 0x0820, 0x0FE0, 0x07C0, 0x0000], // 'p'
+    [0x07C0, 0x0FE0, 0x0820, 0x4820, 0x7FC0, 0x7FE0, 0x4020, 0x0000], // 'q'
+    [0x0820, 0x0FE0, 0x0FC0, 0x0860, 0x0020, 0x00E0, 0x00C0, 0x0000], // 'r'
+    [0x0440, 0x0CE0, 0x09A0, 0x0920, 0x0B20, 0x0E60, 0x0440, 0x0000], // 's'
+    [0x0020, 0x0020, 0x07F8, 0x0FFC, 0x0820, 0x0C20, 0x0400, 0x0000], // 't'
+    [0x07E0, 0x0FE0, 0x0800, 0x0800, 0x07E0, 0x0FE0, 0x0800, 0x0000], // 'u'
+    [0x0000, 0x03E0, 0x07E0, 0x0C00, 0x0C00, 0x07E0, 0x03E0, 0x0000], // 'v'
+    [0x07E0, 0x0FE0, 0x0C00, 0x0780, 0x0C00, 0x0FE0, 0x07E0, 0x0000], // 'w'
+    [0x0820, 0x0C60, 0x07C0, 0x0380, 0x07C0, 0x0C60, 0x0820, 0x0000], // 'x'
+    [0x47E0, 0x4FE0, 0x4800, 0x4800, 0x6800, 0x3FE0, 0x1FE0, 0x0000], // 'y'
+    [0x0C60, 0x0E60, 0x0B20, 0x09A0, 0x08E0, 0x0C60, 0x0C20, 0x0000], // 'z'
+    [0x0000, 0x0040, 0x0040, 0x07F8, 0x0FBC, 0x0804, 0x0804, 0x0000], // '{'
+    [0x0000, 0x0000, 0x0000, 0x3FFC, 0x3FFC, 0x0000, 0x0000, 0x0000], // '|'
+    [0x0000, 0x0804, 0x0804, 0x0FBC, 0x07F8, 0x0040, 0x0040, 0x0000], // '}'
+    [0x0008, 0x000C, 0x0004, 0x000C, 0x0008, 0x000C, 0x0004, 0x0000], // '~'
+];
+
+/// [`COLUMNS_8X16`] split into two bytes per column, top half first.
+pub(super) const ASCII_8X16: [[u8; 16]; 95] = {
+    let mut table = [[0; 16]; 95];
+    let mut i = 0;
+    while i < 95 {
+        let mut col = 0;
+        while col < 8 {
+            let [top, bottom] = COLUMNS_8X16[i][col].to_le_bytes();
+            table[i][2 * col] = top;
+            table[i][2 * col + 1] = bottom;
+            col += 1;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// A few common characters, 16x16 with two bytes per column.
+#[cfg(feature = "cjk")]
+pub(super) const CJK_16X16: &[(char, [u8; 32])] = &[
+    (
+        '一',
+        [
+            0x00, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00,
+            0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00,
+            0x80, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '二',
+        [
+            0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
+            0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x08,
+            0x00, 0x08, 0x00, 0x00,
+        ],
+    ),
+    (
+        '三',
+        [
+            0x00, 0x00, 0x00, 0x10, 0x04, 0x10, 0x84, 0x10, 0x84, 0x10, 0x84, 0x10, 0x84, 0x10,
+            0x84, 0x10, 0x84, 0x10, 0x84, 0x10, 0x84, 0x10, 0x84, 0x10, 0x84, 0x10, 0x04, 0x10,
+            0x00, 0x10, 0x00, 0x00,
+        ],
+    ),
+    (
+        '上',
+        [
+            0x00, 0x00, 0x00, 0x20, 0x00, 0x20, 0x00, 0x20, 0x00, 0x20, 0x00, 0x20, 0x00, 0x20,
+            0xFE, 0x3F, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+            0x00, 0x20, 0x00, 0x00,
+        ],
+    ),
+    (
+        '下',
+        [
+            0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00, 0x02, 0x00,
+            0xFE, 0x7F, 0x12, 0x00, 0x22, 0x00, 0x42, 0x00, 0x82, 0x00, 0x02, 0x00, 0x02, 0x00,
+            0x02, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '中',
+        [
+            0x00, 0x00, 0xF8, 0x01, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01,
+            0xFF, 0x7F, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01, 0x08, 0x01, 0xF8, 0x01,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '大',
+        [
+            0x00, 0x00, 0x10, 0x40, 0x10, 0x20, 0x10, 0x10, 0x10, 0x08, 0x10, 0x06, 0x90, 0x01,
+            0x7F, 0x00, 0x90, 0x01, 0x10, 0x06, 0x10, 0x08, 0x10, 0x10, 0x10, 0x20, 0x10, 0x40,
+            0x10, 0x40, 0x00, 0x00,
+        ],
+    ),
+    (
+        '小',
+        [
+            0x00, 0x00, 0x00, 0x03, 0xC0, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x40,
+            0xFF, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x20, 0x00, 0xC0, 0x00,
+            0x00, 0x03, 0x00, 0x00,
+        ],
+    ),
+    (
+        '开',
+        [
+            0x00, 0x00, 0x40, 0x40, 0x42, 0x20, 0x42, 0x10, 0x42, 0x0C, 0xFE, 0x03, 0x42, 0x00,
+            0x42, 0x00, 0x42, 0x00, 0x42, 0x00, 0xFE, 0x7F, 0x42, 0x00, 0x42, 0x00, 0x42, 0x00,
+            0x40, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '关',
+        [
+            0x00, 0x00, 0x80, 0x20, 0x90, 0x20, 0x90, 0x10, 0x92, 0x08, 0x94, 0x04, 0x98, 0x02,
+            0xF0, 0x01, 0x98, 0x02, 0x94, 0x04, 0x92, 0x08, 0x90, 0x10, 0x90, 0x20, 0x80, 0x20,
+            0x80, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '日',
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x3F, 0x82, 0x20, 0x82, 0x20, 0x82, 0x20,
+            0x82, 0x20, 0x82, 0x20, 0x82, 0x20, 0x82, 0x20, 0x82, 0x20, 0xFE, 0x3F, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+    ),
+    (
+        '月',
+        [
+            0x00, 0x40, 0x00, 0x20, 0x00, 0x10, 0x00, 0x0C, 0xFE, 0x03, 0x92, 0x00, 0x92, 0x00,
+            0x92, 0x00, 0x92, 0x00, 0x92, 0x00, 0x92, 0x20, 0x92, 0x40, 0xFE, 0x7F, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+    ),
+];
@@ -0,0 +1,136 @@
+//! Bitmap fonts and text drawn into a [`FrameBuffer`].
+//!
+//! ```
+//! use core::fmt::Write;
+//! use iic_oled_rs::{text::{Console, FONT_5X7}, FrameBuffer};
+//!
+//! let mut console = Console::new(FrameBuffer::<8>::new(), FONT_5X7);
+//! writeln!(console, "t = {} ms", 42).unwrap();
+//! // oled.flush(console.frame())?;
+//! ```
+
+mod console;
+mod fonts;
+
+pub use console::Console;
+
+use crate::framebuffer::FrameBuffer;
+
+/// Monospace bitmap font, optionally with 16x16 glyphs for characters beyond ASCII.
+#[derive(Debug, Clone, Copy)]
+pub struct Font {
+    width: usize,
+    height: usize,
+    advance: usize,
+    line_height: usize,
+    ascii: &'static [u8],
+    wide: &'static [(char, [u8; 32])],
+}
+
+/// 5x7 ASCII, 6 pixels per character and 8 per line: 21 x 8 characters on a 128x64 panel.
+pub const FONT_5X7: Font = Font {
+    width: 5,
+    height: 7,
+    advance: 6,
+    line_height: 8,
+    ascii: fonts::ASCII_5X7.as_flattened(),
+    wide: &[],
+};
+
+/// 8x16 ASCII, 8 pixels per character and 16 per line: 16 x 4 characters on a 128x64 panel.
+pub const FONT_8X16: Font = Font {
+    width: 8,
+    height: 16,
+    advance: 8,
+    line_height: 16,
+    ascii: fonts::ASCII_8X16.as_flattened(),
+    wide: &[],
+};
+
+/// [`FONT_8X16`] with the built-in CJK subset: 一二三上下中大小开关日月.
+#[cfg(feature = "cjk")]
+pub const FONT_8X16_CJK: Font = FONT_8X16.with_wide(fonts::CJK_16X16);
+
+impl Font {
+    /// Adds 16x16 glyphs, two bytes per column, for the characters ASCII lacks.
+    ///
+    /// Meant for 16 pixel fonts, lines are not made taller to fit them.
+    pub const fn with_wide(mut self, glyphs: &'static [(char, [u8; 32])]) -> Self {
+        self.wide = glyphs;
+        self
+    }
+
+    /// Horizontal pixels taken by `c`.
+    pub fn advance(&self, c: char) -> usize {
+        match self.wide_glyph(c) {
+            Some(_) => 16,
+            None => self.advance,
+        }
+    }
+
+    pub fn line_height(&self) -> usize {
+        self.line_height
+    }
+
+    /// Bitmap, width and height of `c`, `'?'` stands in for missing characters.
+    fn glyph(&self, c: char) -> (&'static [u8], usize, usize) {
+        if let Some(glyph) = self.wide_glyph(c) {
+            return (glyph, 16, 16);
+        }
+        let index = match c {
+            ' '..='~' => c as usize - ' ' as usize,
+            _ => '?' as usize - ' ' as usize,
+        };
+        let size = self.width * self.height.div_ceil(8);
+        (
+            &self.ascii[index * size..(index + 1) * size],
+            self.width,
+            self.height,
+        )
+    }
+
+    fn wide_glyph(&self, c: char) -> Option<&'static [u8]> {
+        if c.is_ascii() {
+            return None;
+        }
+        self.wide
+            .iter()
+            .find(|(wide, _)| *wide == c)
+            .map(|(_, glyph)| &glyph[..])
+    }
+}
+
+impl<const P: usize> FrameBuffer<P> {
+    /// Draws `c` with its top left corner at `(x, y)`, clearing the rest of its cell.
+    pub fn draw_char(&mut self, font: &Font, (x, y): (usize, usize), c: char) {
+        let (glyph, width, height) = font.glyph(c);
+        let bytes = height.div_ceil(8);
+        for col in 0..font.advance(c) {
+            for row in 0..font.line_height.max(height) {
+                let on = col < width
+                    && row < height
+                    && glyph[col * bytes + row / 8] >> (row % 8) & 1 == 1;
+                self.set_pixel(x + col, y + row, on);
+            }
+        }
+    }
+
+    /// Draws `text` from `pos`, wrapping at the right edge and on `'\n'`.
+    ///
+    /// Returns where the next character would go.
+    pub fn draw_text(&mut self, font: &Font, pos: (usize, usize), text: &str) -> (usize, usize) {
+        let (mut x, mut y) = pos;
+        for c in text.chars() {
+            if c == '\n' {
+                (x, y) = (0, y + font.line_height);
+                continue;
+            }
+            if x > 0 && x + font.advance(c) > self.size().0 {
+                (x, y) = (0, y + font.line_height);
+            }
+            self.draw_char(font, (x, y), c);
+            x += font.advance(c);
+        }
+        (x, y)
+    }
+}
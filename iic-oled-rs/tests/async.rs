@@ -0,0 +1,150 @@
+#![cfg(feature = "async")]
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use embedded_hal_1::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+};
+use iic_oled_rs::{
+    consts::{SSD1306_ADDR, SSD1306_ADDR_ALT},
+    interface::eh1::I2cInterface,
+    AsyncOled, Error, PixelShift, Sh1106,
+};
+
+/// The mock never pends, so polling once finishes any of its futures.
+fn block_on<F: Future>(f: F) -> F::Output {
+    match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(out) => out,
+        Poll::Pending => panic!("mock future pending"),
+    }
+}
+
+fn write(addr: u8, control: u8, bytes: &[u8]) -> [I2cTransaction; 4] {
+    [
+        I2cTransaction::transaction_start(addr),
+        I2cTransaction::write(addr, vec![control]),
+        I2cTransaction::write(addr, bytes.to_vec()),
+        I2cTransaction::transaction_end(addr),
+    ]
+}
+
+fn cmd(addr: u8, bytes: &[u8]) -> [I2cTransaction; 4] {
+    write(addr, 0x00, bytes)
+}
+
+#[test]
+fn draw_sets_window_then_writes_frame() {
+    let frame: Vec<u8> = (0..200).map(|i| i as u8).collect();
+    let mut expected: Vec<_> = [&[0x20, 0x00][..], &[0x21, 0x00, 0x7F], &[0x22, 0x00, 0x07]]
+        .into_iter()
+        .flat_map(|c| cmd(SSD1306_ADDR, c))
+        .collect();
+    for chunk in frame.chunks(128) {
+        expected.extend(write(SSD1306_ADDR, 0x40, chunk));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = AsyncOled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    block_on(oled.draw(&frame)).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn sh1106_draw_vertical_writes_transposed_pages() {
+    let frame: Vec<u8> = (0..=255).collect();
+    let mut expected = vec![];
+    for page in 0..2u8 {
+        for c in [0xB0 | page, 0x02, 0x10] {
+            expected.extend(cmd(SSD1306_ADDR, &[c]));
+        }
+        let data: Vec<u8> = frame
+            .iter()
+            .copied()
+            .skip(page as usize)
+            .step_by(2)
+            .collect();
+        expected.extend(write(SSD1306_ADDR, 0x40, &data));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = AsyncOled::<16, _, Sh1106>::new(I2cInterface::new(i2c.clone()));
+
+    block_on(oled.draw_vertical(&frame)).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn sh1106_clear_covers_every_ram_column() {
+    let mut expected = vec![];
+    for page in 0..2u8 {
+        for c in [0xB0 | page, 0x00, 0x10] {
+            expected.extend(cmd(SSD1306_ADDR, &[c]));
+        }
+        expected.extend(write(SSD1306_ADDR, 0x40, &[0; 128]));
+        expected.extend(write(SSD1306_ADDR, 0x40, &[0; 4]));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = AsyncOled::<16, _, Sh1106>::new(I2cInterface::new(i2c.clone()));
+
+    block_on(oled.clear()).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn fade_contrast_steps_to_target() {
+    let expected: Vec<_> = [0xCF - 0x40, 0xCF - 0x80, 0x10]
+        .into_iter()
+        .flat_map(|c| cmd(SSD1306_ADDR, &[0x81, c]))
+        .collect();
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = AsyncOled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    block_on(oled.fade_contrast(0x10, 0x40, &mut NoopDelay::new(), 10)).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn pixel_shift_rejects_panel_height() {
+    let mut i2c = I2cMock::new(&[]);
+    let mut oled = AsyncOled::<32, _>::new(I2cInterface::new(i2c.clone()));
+
+    let mut shift = PixelShift::new(32, 1);
+    assert_eq!(
+        block_on(oled.pixel_shift(&mut shift)),
+        Err(Error::OutOfRange)
+    );
+    i2c.done();
+}
+
+#[test]
+fn probe_skips_nacked_address() {
+    let nop = vec![0x80, 0xE3];
+    let mut expected = vec![
+        I2cTransaction::write(SSD1306_ADDR, nop.clone())
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        I2cTransaction::write(SSD1306_ADDR_ALT, nop),
+    ];
+    expected.extend(cmd(SSD1306_ADDR_ALT, &[0xAF]));
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = AsyncOled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    block_on(oled.probe()).unwrap();
+    block_on(oled.send_one_byte_cmd(0xAF)).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn probe_keeps_bus_errors() {
+    let mut i2c = I2cMock::new(&[I2cTransaction::write(SSD1306_ADDR, vec![0x80, 0xE3])
+        .with_error(ErrorKind::ArbitrationLoss)]);
+    let mut oled = AsyncOled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(
+        block_on(oled.probe()),
+        Err(Error::Bus(ErrorKind::ArbitrationLoss))
+    );
+    i2c.done();
+}
@@ -0,0 +1,368 @@
+#![cfg(feature = "eh02")]
+
+use embedded_hal_mock::eh0::{
+    digital::{Mock as PinMock, State, Transaction as PinTransaction},
+    i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+    spi::{Mock as SpiMock, Transaction as SpiTransaction},
+    MockError,
+};
+use iic_oled_rs::{
+    consts::{SSD1306_ADDR, SSD1306_ADDR_ALT},
+    interface::eh02::{I2cInterface, Spi3WireInterface, SpiInterface},
+    Config, ContrastFade, Error, FrameInterval, Oled, PixelShift, Rotation, Scroll,
+    ScrollDirection, Sh1106, Ssd1309, Vcc,
+};
+
+fn cmd(addr: u8, bytes: &[u8]) -> I2cTransaction {
+    let mut expected = vec![0x00];
+    expected.extend_from_slice(bytes);
+    I2cTransaction::write(addr, expected)
+}
+
+fn init_sequence(addr: u8) -> Vec<I2cTransaction> {
+    [
+        &[0xAE][..],
+        &[0xD5, 0x80],
+        &[0xA8, 0x3F],
+        &[0xD3, 0x00],
+        &[0x8D, 0x14],
+        &[0x40],
+        &[0xA1],
+        &[0xC8],
+        &[0xDA, 0x12],
+        &[0x81, 0xCF],
+        &[0xD9, 0xF1],
+        &[0xDB, 0x40],
+        &[0xA4],
+        &[0xA6],
+        &[0xAF],
+    ]
+    .into_iter()
+    .map(|c| cmd(addr, c))
+    .collect()
+}
+
+#[test]
+fn init_sends_ssd1306_sequence() {
+    let mut i2c = I2cMock::new(&init_sequence(SSD1306_ADDR));
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.init().unwrap();
+    i2c.done();
+}
+
+#[test]
+fn init_applies_config() {
+    let expected: Vec<_> = [
+        &[0xAE][..],
+        &[0xD5, 0xF1],
+        &[0xA8, 0x3F],
+        &[0xD3, 0x00],
+        &[0x8D, 0x10],
+        &[0x40],
+        &[0xA0],
+        &[0xC8],
+        &[0xDA, 0x12],
+        &[0x81, 0x9F],
+        &[0xD9, 0x22],
+        &[0xDB, 0x30],
+        &[0xA4],
+        &[0xA6],
+        &[0xAF],
+    ]
+    .into_iter()
+    .map(|c| cmd(SSD1306_ADDR, c))
+    .collect();
+    let mut i2c = I2cMock::new(&expected);
+    let config = Config::new()
+        .rotation(Rotation::Deg180)
+        .mirror_y(true)
+        .vcc(Vcc::External)
+        .contrast(0x9F)
+        .precharge(0x22)
+        .vcomh(0x30)
+        .clock(2, 0x0F);
+    let mut oled = Oled::<64, _>::with_config(I2cInterface::new(i2c.clone()), config);
+
+    oled.init().unwrap();
+    i2c.done();
+}
+
+#[test]
+fn init_rejects_invalid_geometry() {
+    let mut i2c = I2cMock::new(&[]);
+    let mut oled = Oled::<12, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(oled.init(), Err(Error::InvalidGeometry));
+    i2c.done();
+}
+
+#[test]
+fn set_display_addr_rejects_out_of_range_window() {
+    let mut i2c = I2cMock::new(&[]);
+    let mut oled = Oled::<32, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(
+        oled.set_display_addr((0, 128), (0, 3)),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        oled.set_display_addr((0, 127), (0, 4)),
+        Err(Error::OutOfRange)
+    );
+    i2c.done();
+}
+
+#[test]
+fn sleep_and_wake_switch_charge_pump() {
+    let expected: Vec<_> = [&[0xAE][..], &[0x8D, 0x10], &[0x8D, 0x14], &[0xAF]]
+        .into_iter()
+        .map(|c| cmd(SSD1306_ADDR, c))
+        .collect();
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.sleep().unwrap();
+    oled.wake().unwrap();
+    i2c.done();
+}
+
+#[test]
+fn fade_contrast_steps_from_current_level() {
+    assert_eq!(
+        ContrastFade::new(0x10, 0x40, 0x10).collect::<Vec<_>>(),
+        [0x20, 0x30, 0x40]
+    );
+    assert_eq!(
+        ContrastFade::new(0x30, 0x00, 0x20).collect::<Vec<_>>(),
+        [0x10, 0x00]
+    );
+    assert_eq!(ContrastFade::new(0x30, 0x30, 0x20).count(), 0);
+
+    let expected: Vec<_> = [0xBF, 0xAF, 0x9F]
+        .into_iter()
+        .map(|c| cmd(SSD1306_ADDR, &[0x81, c]))
+        .collect();
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+    let mut waits = 0;
+
+    oled.fade_contrast(0x9F, 0x10, || waits += 1).unwrap();
+    assert_eq!(waits, 2);
+    i2c.done();
+}
+
+#[test]
+fn pixel_shift_bounces_start_line() {
+    let mut shift = PixelShift::new(2, 3);
+    let lines: Vec<_> = (0..18).filter_map(|_| shift.tick()).collect();
+    assert_eq!(lines, [1, 2, 1, 0, 1, 2]);
+
+    let mut i2c = I2cMock::new(&[cmd(SSD1306_ADDR, &[0x41])]);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+    let mut shift = PixelShift::new(4, 2);
+
+    oled.pixel_shift(&mut shift).unwrap();
+    oled.pixel_shift(&mut shift).unwrap();
+    assert!(matches!(oled.set_start_line(64), Err(Error::OutOfRange)));
+    let mut shift = PixelShift::new(64, 1);
+    assert!(matches!(
+        oled.pixel_shift(&mut shift),
+        Err(Error::OutOfRange)
+    ));
+    assert_eq!(shift.line(), 0);
+    i2c.done();
+}
+
+#[test]
+fn probe_falls_back_to_alt_address() {
+    let nop = vec![0x80, 0xE3];
+    let mut expected = vec![
+        I2cTransaction::write(SSD1306_ADDR, nop.clone())
+            .with_error(MockError::Io(std::io::ErrorKind::Other)),
+        I2cTransaction::write(SSD1306_ADDR_ALT, nop),
+    ];
+    expected.extend(init_sequence(SSD1306_ADDR_ALT));
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.probe().unwrap();
+    oled.init().unwrap();
+    assert_eq!(oled.release().addr(), SSD1306_ADDR_ALT);
+    i2c.done();
+}
+
+#[test]
+fn probe_reports_missing_panel() {
+    let nop = vec![0x80, 0xE3];
+    let err = MockError::Io(std::io::ErrorKind::Other);
+    let mut i2c = I2cMock::new(&[
+        I2cTransaction::write(SSD1306_ADDR, nop.clone()).with_error(err.clone()),
+        I2cTransaction::write(SSD1306_ADDR_ALT, nop).with_error(err),
+    ]);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(oled.probe(), Err(Error::NotFound));
+    i2c.done();
+}
+
+#[test]
+fn sh1106_draw_vertical_writes_transposed_pages() {
+    let frame: Vec<u8> = (0..=255).collect();
+    let mut expected = vec![];
+    for page in 0..2u8 {
+        expected.push(cmd(SSD1306_ADDR, &[0xB0 | page]));
+        expected.push(cmd(SSD1306_ADDR, &[0x02]));
+        expected.push(cmd(SSD1306_ADDR, &[0x10]));
+        let mut data = vec![0x40];
+        data.extend(frame.iter().skip(page as usize).step_by(2));
+        expected.push(I2cTransaction::write(SSD1306_ADDR, data));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<16, _, Sh1106>::new(I2cInterface::new(i2c.clone()));
+
+    oled.draw_vertical(&frame).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn draw_sends_only_valid_bytes_of_last_chunk() {
+    let frame: Vec<u8> = (0..200).map(|i| i as u8 | 0x01).collect();
+    let mut expected = vec![
+        cmd(SSD1306_ADDR, &[0x20, 0x00]),
+        cmd(SSD1306_ADDR, &[0x21, 0x00, 0x7F]),
+        cmd(SSD1306_ADDR, &[0x22, 0x00, 0x07]),
+    ];
+    for chunk in frame.chunks(128) {
+        let mut data = vec![0x40];
+        data.extend_from_slice(chunk);
+        expected.push(I2cTransaction::write(SSD1306_ADDR, data));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.draw(&frame).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn start_scroll_sends_setup_between_deactivate_and_activate() {
+    let mut i2c = I2cMock::new(&[
+        cmd(SSD1306_ADDR, &[0x2E]),
+        cmd(SSD1306_ADDR, &[0x27, 0x00, 0x00, 0x07, 0x07, 0x00, 0xFF]),
+        cmd(SSD1306_ADDR, &[0x2F]),
+        cmd(SSD1306_ADDR, &[0x2E]),
+        cmd(SSD1306_ADDR, &[0x29, 0x00, 0x02, 0x00, 0x03, 0x01]),
+        cmd(SSD1306_ADDR, &[0x2F]),
+    ]);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.start_scroll(&Scroll::horizontal(
+        ScrollDirection::Left,
+        (0, 7),
+        FrameInterval::Frames2,
+    ))
+    .unwrap();
+    oled.start_scroll(&Scroll::diagonal(
+        ScrollDirection::Right,
+        (2, 3),
+        FrameInterval::Frames5,
+        1,
+    ))
+    .unwrap();
+    assert_eq!(
+        oled.start_scroll(&Scroll::horizontal(
+            ScrollDirection::Left,
+            (0, 8),
+            FrameInterval::Frames2,
+        )),
+        Err(Error::OutOfRange)
+    );
+    i2c.done();
+}
+
+#[test]
+fn ssd1309_scroll_setup_ends_with_columns() {
+    let mut i2c = I2cMock::new(&[
+        cmd(SSD1306_ADDR, &[0x2E]),
+        cmd(
+            SSD1306_ADDR,
+            &[0x27, 0x00, 0x00, 0x07, 0x07, 0x00, 0x00, 0x7F],
+        ),
+        cmd(SSD1306_ADDR, &[0x2F]),
+        cmd(SSD1306_ADDR, &[0x2E]),
+        cmd(
+            SSD1306_ADDR,
+            &[0x29, 0x01, 0x02, 0x00, 0x03, 0x01, 0x00, 0x7F],
+        ),
+        cmd(SSD1306_ADDR, &[0x2F]),
+    ]);
+    let mut oled = Oled::<64, _, Ssd1309>::new(I2cInterface::new(i2c.clone()));
+
+    oled.start_scroll(&Scroll::horizontal(
+        ScrollDirection::Left,
+        (0, 7),
+        FrameInterval::Frames2,
+    ))
+    .unwrap();
+    oled.start_scroll(&Scroll::diagonal(
+        ScrollDirection::Right,
+        (2, 3),
+        FrameInterval::Frames5,
+        1,
+    ))
+    .unwrap();
+    i2c.done();
+}
+
+#[test]
+fn spi_toggles_dc_and_cs() {
+    let mut spi = SpiMock::new(&[
+        SpiTransaction::write(vec![0x81, 0x7F]),
+        SpiTransaction::write(vec![0xFF, 0x00]),
+    ]);
+    let mut dc = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    let mut cs = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    let mut oled = Oled::<64, _>::new(SpiInterface::new(spi.clone(), dc.clone(), cs.clone()));
+
+    oled.send_cmd(&[0x81, 0x7F]).unwrap();
+    oled.send_data(&[0xFF, 0x00]).unwrap();
+    spi.done();
+    dc.done();
+    cs.done();
+}
+
+/// Reference packing, one bit at a time.
+fn pack_3wire(words: &[(bool, u8)]) -> Vec<u8> {
+    let bits: Vec<bool> = words
+        .iter()
+        .flat_map(|(dc, b)| std::iter::once(*dc).chain((0..8).rev().map(move |i| b >> i & 1 == 1)))
+        .collect();
+    bits.chunks(8)
+        .map(|c| c.iter().fold(0u8, |acc, bit| acc << 1 | *bit as u8))
+        .collect()
+}
+
+#[test]
+fn spi_3wire_packs_9bit_words() {
+    let mut words = vec![(true, 0xA5), (true, 0x01), (true, 0xFF)];
+    words.resize(8, (false, 0xE3));
+    let mut spi = SpiMock::new(&[SpiTransaction::write(pack_3wire(&words))]);
+    let mut cs = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    let mut oled = Oled::<64, _>::new(Spi3WireInterface::new(spi.clone(), cs.clone()));
+
+    oled.send_data(&[0xA5, 0x01, 0xFF]).unwrap();
+    spi.done();
+    cs.done();
+}
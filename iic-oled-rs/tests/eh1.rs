@@ -0,0 +1,156 @@
+#![cfg(feature = "eh1")]
+
+use embedded_hal_1::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::{
+    digital::{Mock as PinMock, State, Transaction as PinTransaction},
+    i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+    spi::{Mock as SpiMock, Transaction as SpiTransaction},
+};
+use iic_oled_rs::{
+    consts::{SSD1306_ADDR, SSD1306_ADDR_ALT},
+    interface::eh1::{I2cInterface, Spi3WireInterface, SpiInterface},
+    Error, Oled, Ssd1309,
+};
+
+/// One transfer, control byte and payload written without a copy in between.
+fn write(addr: u8, control: u8, bytes: &[u8]) -> [I2cTransaction; 4] {
+    [
+        I2cTransaction::transaction_start(addr),
+        I2cTransaction::write(addr, vec![control]),
+        I2cTransaction::write(addr, bytes.to_vec()),
+        I2cTransaction::transaction_end(addr),
+    ]
+}
+
+fn cmd(addr: u8, bytes: &[u8]) -> [I2cTransaction; 4] {
+    write(addr, 0x00, bytes)
+}
+
+#[test]
+fn ssd1309_init_skips_charge_pump() {
+    let expected: Vec<_> = [
+        &[0xAE][..],
+        &[0xD5, 0x80],
+        &[0xA8, 0x1F],
+        &[0xD3, 0x00],
+        &[0x40],
+        &[0xA1],
+        &[0xC8],
+        &[0xDA, 0x02],
+        &[0x81, 0xCF],
+        &[0xD9, 0xF1],
+        &[0xDB, 0x40],
+        &[0xA4],
+        &[0xA6],
+        &[0xAF],
+    ]
+    .into_iter()
+    .flat_map(|c| cmd(SSD1306_ADDR, c))
+    .collect();
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<32, _, Ssd1309>::new(I2cInterface::new(i2c.clone()));
+
+    oled.init().unwrap();
+    i2c.done();
+}
+
+#[test]
+fn draw_writes_exact_chunks_in_one_transaction_each() {
+    let frame: Vec<u8> = (0..200).map(|i| i as u8 | 0x01).collect();
+    let mut expected: Vec<_> = [&[0x20, 0x00][..], &[0x21, 0x00, 0x7F], &[0x22, 0x00, 0x07]]
+        .into_iter()
+        .flat_map(|c| cmd(SSD1306_ADDR, c))
+        .collect();
+    for chunk in frame.chunks(128) {
+        expected.extend(write(SSD1306_ADDR, 0x40, chunk));
+    }
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _, Ssd1309>::new(I2cInterface::new(i2c.clone()));
+
+    oled.draw(&frame).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn probe_skips_nacked_address() {
+    let nop = vec![0x80, 0xE3];
+    let mut expected = vec![
+        I2cTransaction::write(SSD1306_ADDR, nop.clone())
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        I2cTransaction::write(SSD1306_ADDR_ALT, nop),
+    ];
+    expected.extend(cmd(SSD1306_ADDR_ALT, &[0xAF]));
+    let mut i2c = I2cMock::new(&expected);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    oled.probe().unwrap();
+    oled.send_one_byte_cmd(0xAF).unwrap();
+    i2c.done();
+}
+
+#[test]
+fn probe_reports_missing_panel() {
+    let nop = vec![0x80, 0xE3];
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let mut i2c = I2cMock::new(&[
+        I2cTransaction::write(SSD1306_ADDR, nop.clone()).with_error(nack),
+        I2cTransaction::write(SSD1306_ADDR_ALT, nop).with_error(nack),
+    ]);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(oled.probe(), Err(Error::NotFound));
+    i2c.done();
+}
+
+#[test]
+fn probe_keeps_bus_errors() {
+    let mut i2c = I2cMock::new(&[I2cTransaction::write(SSD1306_ADDR, vec![0x80, 0xE3])
+        .with_error(ErrorKind::ArbitrationLoss)]);
+    let mut oled = Oled::<64, _>::new(I2cInterface::new(i2c.clone()));
+
+    assert_eq!(oled.probe(), Err(Error::Bus(ErrorKind::ArbitrationLoss)));
+    i2c.done();
+}
+
+#[test]
+fn spi_sets_dc_per_transfer() {
+    let mut spi = SpiMock::new(&[
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x7F]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0xFF, 0x00]),
+        SpiTransaction::transaction_end(),
+    ]);
+    let mut dc = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    let mut oled = Oled::<64, _>::new(SpiInterface::new(spi.clone(), dc.clone()));
+
+    oled.send_cmd(&[0x81, 0x7F]).unwrap();
+    oled.send_data(&[0xFF, 0x00]).unwrap();
+    spi.done();
+    dc.done();
+}
+
+#[test]
+fn spi_3wire_packs_9bit_words() {
+    // 0 1010_1111, then 7 NOPs: 0 1110_0011
+    let nop = "011100011";
+    let bits = format!("010101111{}", nop.repeat(7));
+    let packed: Vec<u8> = bits
+        .as_bytes()
+        .chunks(8)
+        .map(|c| u8::from_str_radix(std::str::from_utf8(c).unwrap(), 2).unwrap())
+        .collect();
+    let mut spi = SpiMock::new(&[
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(packed),
+        SpiTransaction::transaction_end(),
+    ]);
+    let mut oled = Oled::<64, _>::new(Spi3WireInterface::new(spi.clone()));
+
+    oled.send_one_byte_cmd(0xAF).unwrap();
+    spi.done();
+}
@@ -0,0 +1,76 @@
+use iic_oled_rs::{FrameBuffer, Orientation};
+
+fn lit(fb: &FrameBuffer<8>) -> Vec<(usize, usize)> {
+    fb.as_bytes()
+        .iter()
+        .enumerate()
+        .flat_map(|(i, byte)| {
+            (0..8)
+                .filter(move |bit| byte >> bit & 1 == 1)
+                .map(move |bit| (i % 128, i / 128 * 8 + bit))
+        })
+        .collect()
+}
+
+#[test]
+fn quarter_turns_swap_size() {
+    let mut fb = FrameBuffer::<8>::new();
+    assert_eq!(fb.size(), (128, 64));
+    fb.set_orientation(Orientation::Deg90);
+    assert_eq!(fb.size(), (64, 128));
+    fb.set_orientation(Orientation::Deg270);
+    assert_eq!(fb.size(), (64, 128));
+}
+
+#[test]
+fn orientation_maps_origin_to_panel_corner() {
+    let cases = [
+        (Orientation::Deg0, (0, 0)),
+        (Orientation::Deg90, (127, 0)),
+        (Orientation::Deg180, (127, 63)),
+        (Orientation::Deg270, (0, 63)),
+    ];
+    for (orientation, panel) in cases {
+        let mut fb = FrameBuffer::<8>::new();
+        fb.set_orientation(orientation);
+        fb.set_pixel(0, 0, true);
+        assert_eq!(lit(&fb), [panel], "{orientation:?}");
+        assert!(fb.pixel(0, 0));
+    }
+}
+
+#[test]
+fn deg90_moves_along_panel_rows() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.set_orientation(Orientation::Deg90);
+    fb.set_pixel(10, 3, true);
+    assert_eq!(lit(&fb), [(124, 10)]);
+}
+
+#[test]
+fn mirror_applies_before_rotation() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.set_orientation(Orientation::Deg90);
+    fb.set_mirror(true, false);
+    fb.set_pixel(0, 0, true);
+    assert_eq!(lit(&fb), [(127, 63)]);
+}
+
+#[test]
+fn out_of_bounds_writes_are_ignored() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.set_orientation(Orientation::Deg90);
+    fb.set_pixel(64, 0, true);
+    fb.set_pixel(0, 128, true);
+    assert!(lit(&fb).is_empty());
+    assert!(!fb.pixel(64, 0));
+}
+
+#[test]
+fn clear_resets_pixels() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.fill(true);
+    assert_eq!(lit(&fb).len(), 128 * 64);
+    fb.clear();
+    assert!(lit(&fb).is_empty());
+}
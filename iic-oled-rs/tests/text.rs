@@ -0,0 +1,97 @@
+use core::fmt::Write;
+
+use iic_oled_rs::{
+    text::{Console, FONT_5X7, FONT_8X16},
+    FrameBuffer, Orientation,
+};
+
+fn column(fb: &FrameBuffer<8>, x: usize, height: usize) -> u32 {
+    (0..height).fold(0, |acc, y| acc | (fb.pixel(x, y) as u32) << y)
+}
+
+#[test]
+fn draws_5x7_glyph_columns() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.draw_char(&FONT_5X7, (0, 0), 'A');
+    let cols: Vec<_> = (0..6).map(|x| column(&fb, x, 8)).collect();
+    assert_eq!(cols, [0x7E, 0x11, 0x11, 0x11, 0x7E, 0x00]);
+}
+
+#[test]
+fn draws_8x16_glyph_columns() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.draw_char(&FONT_8X16, (0, 0), 'A');
+    let cols: Vec<_> = (0..8).map(|x| column(&fb, x, 16)).collect();
+    assert_eq!(
+        cols,
+        [0x0FE0, 0x0FF0, 0x0098, 0x008C, 0x0098, 0x0FF0, 0x0FE0, 0x0000]
+    );
+}
+
+#[test]
+fn missing_characters_draw_question_mark() {
+    let mut a = FrameBuffer::<8>::new();
+    let mut b = FrameBuffer::<8>::new();
+    a.draw_char(&FONT_5X7, (0, 0), 'é');
+    b.draw_char(&FONT_5X7, (0, 0), '?');
+    assert_eq!(a.as_bytes(), b.as_bytes());
+}
+
+#[test]
+fn draw_text_wraps_at_right_edge() {
+    let mut fb = FrameBuffer::<8>::new();
+    let text = "0123456789".repeat(3);
+    // 21 characters of 6 pixels fit in 128 columns
+    assert_eq!(fb.draw_text(&FONT_5X7, (0, 0), &text), (9 * 6, 8));
+    assert_eq!(fb.draw_text(&FONT_5X7, (0, 0), "ab\nc"), (6, 8));
+}
+
+#[test]
+fn draw_text_follows_orientation() {
+    let mut fb = FrameBuffer::<8>::new();
+    fb.set_orientation(Orientation::Deg90);
+    // 64 pixels wide: 10 characters per line
+    assert_eq!(fb.draw_text(&FONT_5X7, (0, 0), "0123456789ab"), (12, 8));
+}
+
+#[test]
+fn console_scrolls_when_full() {
+    let mut console = Console::new(FrameBuffer::<8>::new(), FONT_5X7);
+    for line in 0..8 {
+        writeln!(console, "{line}").unwrap();
+    }
+    let mut expected = FrameBuffer::<8>::new();
+    for line in 0..8 {
+        expected.draw_text(&FONT_5X7, (0, line * 8), &line.to_string());
+    }
+    assert_eq!(console.frame().as_bytes(), expected.as_bytes());
+
+    write!(console, "8").unwrap();
+    let mut scrolled = FrameBuffer::<8>::new();
+    for line in 1..9 {
+        scrolled.draw_text(&FONT_5X7, (0, (line - 1) * 8), &line.to_string());
+    }
+    assert_eq!(console.frame().as_bytes(), scrolled.as_bytes());
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn cjk_glyphs_take_16_columns() {
+    use iic_oled_rs::text::FONT_8X16_CJK;
+
+    let mut fb = FrameBuffer::<8>::new();
+    assert_eq!(fb.draw_text(&FONT_8X16_CJK, (0, 0), "A一"), (24, 0));
+    // horizontal stroke on row 7 from column 1 to 14 of the glyph
+    assert!((9..23).all(|x| fb.pixel(x, 7)));
+    assert!(!fb.pixel(8, 7) && !fb.pixel(23, 7));
+}
+
+#[test]
+fn console_clips_font_taller_than_frame() {
+    let mut console = Console::new(FrameBuffer::<1>::new(), FONT_8X16);
+    write!(console, "A\nB").unwrap();
+
+    let mut expected = FrameBuffer::<1>::new();
+    expected.draw_char(&FONT_8X16, (0, 0), 'B');
+    assert_eq!(console.frame().as_bytes(), expected.as_bytes());
+}
@@ -0,0 +1,264 @@
+//! I2C1 interface for iic-oled-rs on STM32F1, frame data sent by DMA1 channel 6
+//! in the background.
+//!
+//! ```ignore
+//! let buffer = cortex_m::singleton!(: [u8; 1024] = [0; 1024]).unwrap();
+//! let mut oled = Oled::<64, _>::new(DmaI2cInterface::new(i2c, dma1.6, buffer));
+//! oled.init()?;
+//! oled.draw(&frame)?;
+//! loop {
+//!     // ends the transfer once the DMA is done, bus errors show up here
+//!     if let Err(nb::Error::Other(e)) = oled.interface_mut().poll() {}
+//!     // serve USB meanwhile
+//! }
+//! ```
+
+#![no_std]
+#![deny(unsafe_code)]
+
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use embedded_hal::blocking::i2c::Write;
+use iic_oled_rs::consts;
+use iic_oled_rs::interface::{self, Interface};
+use stm32f1xx_hal::dma::dma1::C6;
+use stm32f1xx_hal::i2c::{BlockingI2c, Error};
+use stm32f1xx_hal::pac::{self, I2C1};
+
+/// Status register reads spent waiting on one flag, a few milliseconds at 72 MHz.
+pub const DEFAULT_TIMEOUT: u32 = 100_000;
+
+/// Commands go out through `BlockingI2c`, data is copied into `buffer` and sent by DMA
+/// while the caller carries on.
+///
+/// The next transfer waits for the previous one, call `poll` from the main loop to
+/// end a finished transfer early and pick up its errors.
+pub struct DmaI2cInterface<PINS> {
+    i2c: BlockingI2c<I2C1, PINS>,
+    dma: C6,
+    buffer: &'static mut [u8],
+    addr: u8,
+    timeout: u32,
+    busy: bool,
+}
+
+impl<PINS> DmaI2cInterface<PINS> {
+    /// Data longer than `buffer` is sent in several DMA transfers.
+    pub fn new(i2c: BlockingI2c<I2C1, PINS>, dma: C6, buffer: &'static mut [u8]) -> Self {
+        DmaI2cInterface {
+            i2c,
+            dma,
+            buffer,
+            addr: consts::SSD1306_ADDR,
+            timeout: DEFAULT_TIMEOUT,
+            busy: false,
+        }
+    }
+
+    pub fn with_addr(mut self, addr: u8) -> Self {
+        self.addr = addr;
+        self
+    }
+
+    /// Status register reads before a stuck flag or a stalled DMA gives `Error::Timeout`.
+    pub fn with_timeout(mut self, polls: u32) -> Self {
+        self.timeout = polls.max(1);
+        self
+    }
+
+    pub fn addr(&self) -> u8 {
+        self.addr
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    /// Waits for the last transfer before handing the parts back.
+    pub fn release(mut self) -> (BlockingI2c<I2C1, PINS>, C6, &'static mut [u8]) {
+        let _ = self.wait_idle();
+        (self.i2c, self.dma, self.buffer)
+    }
+
+    /// Ends the transfer with STOP once the DMA is done, `Ok` when idle.
+    ///
+    /// A NACK or bus fault during the transfer aborts it and is returned here.
+    pub fn poll(&mut self) -> nb::Result<(), Error> {
+        if !self.busy {
+            return Ok(());
+        }
+        let i2c = regs();
+        if self.dma.in_progress() {
+            return match bus_error(i2c) {
+                Some(e) => {
+                    self.finish();
+                    Err(nb::Error::Other(e))
+                }
+                None => Err(nb::Error::WouldBlock),
+            };
+        }
+
+        let res = wait_flag(self.timeout, || i2c.sr1.read().btf().bit_is_set());
+        self.finish();
+        res.map_err(nb::Error::Other)
+    }
+
+    /// DMA buffer to fill without a copy, `None` while it is being sent.
+    pub fn buffer(&mut self) -> Option<&mut [u8]> {
+        (!self.busy).then_some(&mut *self.buffer)
+    }
+
+    /// Sends the first `len` bytes of the buffer as display data in the background.
+    pub fn start(&mut self, len: usize) -> Result<(), Error> {
+        self.wait_idle()?;
+        let len = len.min(self.buffer.len());
+
+        let i2c = regs();
+        self.address(i2c).inspect_err(|_| {
+            i2c.cr1.modify(|_, w| w.stop().set_bit());
+        })?;
+        // the control byte goes in by hand so the data needs no copy behind it
+        i2c.dr.write(|w| w.dr().bits(0x40));
+
+        self.dma
+            .set_peripheral_address(&i2c.dr as *const _ as u32, false);
+        self.dma
+            .set_memory_address(self.buffer.as_ptr() as u32, true);
+        self.dma.set_transfer_length(len);
+        self.dma.ch().cr.modify(|_, w| {
+            w.mem2mem()
+                .clear_bit()
+                .pl()
+                .medium()
+                .msize()
+                .bits8()
+                .psize()
+                .bits8()
+                .circ()
+                .clear_bit()
+                .dir()
+                .set_bit()
+        });
+        compiler_fence(Ordering::Release);
+        i2c.cr2.modify(|_, w| w.dmaen().set_bit());
+        self.dma.start();
+        self.busy = true;
+        Ok(())
+    }
+
+    /// Generates START and sends the write address.
+    fn address(&self, i2c: &pac::i2c1::RegisterBlock) -> Result<(), Error> {
+        i2c.cr1.modify(|_, w| w.start().set_bit());
+        wait_flag(self.timeout, || i2c.sr1.read().sb().bit_is_set())?;
+        i2c.dr.write(|w| w.dr().bits(self.addr << 1));
+        wait_flag(self.timeout, || i2c.sr1.read().addr().bit_is_set())?;
+        // ADDR is cleared by reading SR1 then SR2
+        let _ = i2c.sr1.read();
+        let _ = i2c.sr2.read();
+        Ok(())
+    }
+
+    /// Blocks on `poll`, giving up when the DMA stops moving for `timeout` polls.
+    fn wait_idle(&mut self) -> Result<(), Error> {
+        let mut left = self.dma.get_ndtr();
+        let mut polls = 0;
+        loop {
+            match self.poll() {
+                Err(nb::Error::WouldBlock) => {}
+                Err(nb::Error::Other(e)) => return Err(e),
+                Ok(()) => return Ok(()),
+            }
+            let ndtr = self.dma.get_ndtr();
+            if ndtr != left {
+                left = ndtr;
+                polls = 0;
+            } else if polls == self.timeout {
+                self.finish();
+                return Err(Error::Timeout);
+            } else {
+                polls += 1;
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        let i2c = regs();
+        i2c.cr1.modify(|_, w| w.stop().set_bit());
+        i2c.cr2.modify(|_, w| w.dmaen().clear_bit());
+        self.dma.stop();
+        compiler_fence(Ordering::Acquire);
+        self.busy = false;
+    }
+}
+
+impl<PINS> Interface for DmaI2cInterface<PINS> {
+    type Error = Error;
+
+    /// Sent with `BlockingI2c` after the data transfer in flight.
+    fn send_commands<const N: usize>(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.wait_idle()?;
+        let mut buffer = [0; N];
+        cmds.chunks(N - 1).try_for_each(|c| {
+            buffer[1..=c.len()].copy_from_slice(c);
+            self.i2c.write(self.addr, &buffer[..=c.len()])
+        })
+    }
+
+    /// Copied into the DMA buffer and sent in the background, `N` is not used.
+    fn send_data<const N: usize>(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        for c in data.chunks(self.buffer.len()) {
+            self.wait_idle()?;
+            self.buffer[..c.len()].copy_from_slice(c);
+            self.start(c.len())?;
+        }
+        Ok(())
+    }
+
+    fn probe(&mut self) -> Result<(), iic_oled_rs::Error<Self::Error>> {
+        self.wait_idle()?;
+        let i2c = &mut self.i2c;
+        self.addr = interface::probe_addr(
+            |addr, bytes| i2c.write(addr, bytes),
+            |e| matches!(e, Error::Acknowledge),
+        )?;
+        Ok(())
+    }
+}
+
+#[allow(unsafe_code)]
+fn regs() -> &'static pac::i2c1::RegisterBlock {
+    // SAFETY: only used while `BlockingI2c` is idle, the two never touch I2C1 at once
+    unsafe { &*I2C1::ptr() }
+}
+
+/// Clears and returns a NACK, bus error or arbitration loss.
+fn bus_error(i2c: &pac::i2c1::RegisterBlock) -> Option<Error> {
+    let sr1 = i2c.sr1.read();
+    if sr1.af().bit_is_set() {
+        i2c.sr1.modify(|_, w| w.af().clear_bit());
+        return Some(Error::Acknowledge);
+    }
+    if sr1.berr().bit_is_set() {
+        i2c.sr1.modify(|_, w| w.berr().clear_bit());
+        return Some(Error::Bus);
+    }
+    if sr1.arlo().bit_is_set() {
+        i2c.sr1.modify(|_, w| w.arlo().clear_bit());
+        return Some(Error::Arbitration);
+    }
+    None
+}
+
+/// Polls `flag` up to `polls` times, stopping early on a bus error.
+fn wait_flag(polls: u32, flag: impl Fn() -> bool) -> Result<(), Error> {
+    let i2c = regs();
+    for _ in 0..polls {
+        if let Some(e) = bus_error(i2c) {
+            return Err(e);
+        }
+        if flag() {
+            return Ok(());
+        }
+    }
+    Err(Error::Timeout)
+}
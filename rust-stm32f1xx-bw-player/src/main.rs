@@ -2,17 +2,21 @@
 #![no_main]
 #![deny(unsafe_code)]
 
-use bw_img_comm::{Signal, FULL_DATA_BYTE};
+use bw_img_comm::{Signal, FULL_DATA_BYTE, SCROLL_BYTE, SCROLL_DATA_LEN};
 use cortex_m::asm::delay;
 use defmt::println;
 use defmt_rtt as _;
 use fugit::RateExtU32;
-use iic_oled_rs::Oled;
+use iic_oled_rs::{Error, Oled};
+#[cfg(not(feature = "sh1106"))]
+use iic_oled_rs::{FrameInterval, Scroll, ScrollDirection};
+use iic_oled_stm32f1::DmaI2cInterface;
 use panic_probe as _;
 
 use cortex_m_rt::entry;
 use stm32f1xx_hal::afio::AfioExt;
-use stm32f1xx_hal::gpio::GpioExt;
+use stm32f1xx_hal::dma::DmaExt;
+use stm32f1xx_hal::gpio::{Alternate, GpioExt, OpenDrain, PB6, PB7};
 use stm32f1xx_hal::i2c::{BlockingI2c, DutyCycle, Mode};
 use stm32f1xx_hal::pac;
 use stm32f1xx_hal::prelude::_stm32_hal_flash_FlashExt;
@@ -23,6 +27,18 @@ use usb_device::device::{UsbDeviceBuilder, UsbVidPid};
 use usb_device::UsbError;
 use usbd_serial::{SerialPort, USB_CLASS_CDC};
 
+#[cfg(not(any(feature = "sh1106", feature = "ssd1309")))]
+type Controller = iic_oled_rs::Ssd1306;
+#[cfg(feature = "sh1106")]
+type Controller = iic_oled_rs::Sh1106;
+#[cfg(all(feature = "ssd1309", not(feature = "sh1106")))]
+type Controller = iic_oled_rs::Ssd1309;
+
+type Display =
+    Oled<64, DmaI2cInterface<(PB6<Alternate<OpenDrain>>, PB7<Alternate<OpenDrain>>)>, Controller>;
+
+const FRAME_SIZE: usize = 128 * 8;
+
 #[entry]
 fn main() -> ! {
     // 获取对外设的访问对象
@@ -61,10 +77,15 @@ fn main() -> ! {
         1000,
         1000,
     );
-    let mut oled = Oled::<64, _>::new(i2c);
-    oled.init().unwrap();
-    oled.vertical_mem_mode().unwrap();
-    oled.clear().unwrap();
+    // 整帧数据走DMA1通道6(I2C1_TX)后台发送, 不阻塞USB
+    let dma1 = dp.DMA1.split();
+    let frame = cortex_m::singleton!(: [u8; FRAME_SIZE] = [0; FRAME_SIZE]).unwrap();
+    let mut oled: Display = Oled::new(DmaI2cInterface::new(i2c, dma1.6, frame));
+    match oled.probe().and_then(|_| oled.init()).and_then(|_| oled.clear()) {
+        Ok(()) => {}
+        Err(Error::NotFound) => panic!("oled not found at 0x3C/0x3D"),
+        Err(e) => panic!("oled init failed: {:?}", e),
+    }
 
     println!("init usb serial");
     let mut gpioa = dp.GPIOA.split();
@@ -87,10 +108,20 @@ fn main() -> ! {
         .build();
 
     let mut opcode = [0u8; 1];
-    let mut buffer = [0u8; { 128 * 8 }];
+    #[cfg(not(feature = "sh1106"))]
+    let mut buffer = [0u8; SCROLL_DATA_LEN];
+    // SH1106没有垂直寻址, 整帧要转置后按页发送, 不能直接读进DMA缓冲区
+    #[cfg(feature = "sh1106")]
+    let mut buffer = [0u8; FRAME_SIZE];
+    #[cfg(not(feature = "sh1106"))]
+    let mut scrolling = false;
 
     println!("start main loop");
     loop {
+        // DMA发送完后及时结束I2C传输
+        if let Err(nb::Error::Other(e)) = oled.interface_mut().poll() {
+            println!("frame transfer failed: {}", defmt::Debug2Format(&e));
+        }
         if !usb_dev.poll(&mut [&mut serial]) {
             continue;
         }
@@ -101,31 +132,68 @@ fn main() -> ! {
             continue;
         }
 
-        // 从串口读取数据
-        let signal = match opcode[0] {
-            FULL_DATA_BYTE => {
-                let mut offset = 0;
-                while offset < buffer.len() {
-                    match serial.read(&mut buffer[offset..]) {
-                        Ok(count) => {
-                            offset += count;
-                        }
-                        Err(UsbError::WouldBlock) => {
-                            continue;
-                        }
-                        Err(e) => {
-                            panic!("read error: {:?}", e);
-                        }
-                    }
+        if opcode[0] == FULL_DATA_BYTE {
+            // 滚动时写入显存可能会错乱, 先停止滚动
+            #[cfg(not(feature = "sh1106"))]
+            if scrolling {
+                scrolling = false;
+                if let Err(e) = oled.stop_scroll() {
+                    println!("stop scroll failed: {}", defmt::Debug2Format(&e));
                 }
-                Signal::FullData(&buffer)
             }
-            _ => Signal::new(opcode[0], None).unwrap(),
+            #[cfg(not(feature = "sh1106"))]
+            let res = draw_frame(&mut oled, &mut serial);
+            #[cfg(feature = "sh1106")]
+            let res = {
+                serial_read(&mut serial, &mut buffer);
+                oled.draw_vertical(&buffer)
+            };
+            // 显示失败时丢弃这一帧, 仍然回复ACK以免上位机阻塞
+            if let Err(e) = res {
+                println!("draw frame failed: {}", defmt::Debug2Format(&e));
+            }
+            serial_write(&mut serial, Signal::CommACK);
+            continue;
+        }
+
+        // 从串口读取数据
+        let data = match opcode[0] {
+            SCROLL_BYTE => {
+                serial_read(&mut serial, &mut buffer[..SCROLL_DATA_LEN]);
+                Some(&buffer[..SCROLL_DATA_LEN])
+            }
+            _ => None,
+        };
+        let signal = match Signal::new(opcode[0], data) {
+            Ok(signal) => signal,
+            Err(e) => {
+                println!("invalid signal: {}", defmt::Debug2Format(&e));
+                continue;
+            }
         };
 
         match signal {
-            Signal::FullData(data) => {
-                oled.send_data(data).unwrap();
+            #[cfg(not(feature = "sh1106"))]
+            Signal::Scroll(data) => {
+                match parse_scroll(data).map(|scroll| oled.start_scroll(&scroll)) {
+                    Some(Ok(())) => scrolling = true,
+                    Some(Err(e)) => println!("start scroll failed: {}", defmt::Debug2Format(&e)),
+                    None => println!("invalid scroll params: {}", data),
+                }
+                serial_write(&mut serial, Signal::CommACK)
+            }
+            #[cfg(not(feature = "sh1106"))]
+            Signal::StopScroll => {
+                if let Err(e) = oled.stop_scroll() {
+                    println!("stop scroll failed: {}", defmt::Debug2Format(&e));
+                }
+                scrolling = false;
+                serial_write(&mut serial, Signal::CommACK)
+            }
+            // SH1106没有硬件滚动
+            #[cfg(feature = "sh1106")]
+            Signal::Scroll(_) | Signal::StopScroll => {
+                println!("scrolling not supported by SH1106");
                 serial_write(&mut serial, Signal::CommACK)
             }
             _ => continue,
@@ -133,6 +201,63 @@ fn main() -> ! {
     }
 }
 
+/// 串口数据直接读进DMA缓冲区, 设置好显存窗口后在后台发送
+#[cfg(not(feature = "sh1106"))]
+fn draw_frame<B: UsbBus>(
+    oled: &mut Display,
+    serial: &mut SerialPort<B>,
+) -> Result<(), Error<stm32f1xx_hal::i2c::Error>> {
+    let interface = oled.interface_mut();
+    // 等上一帧发送完才能覆盖缓冲区, 出错时缓冲区已经空闲
+    let res = nb::block!(interface.poll());
+    serial_read(serial, interface.buffer().unwrap());
+    res?;
+
+    oled.vertical_mem_mode()?;
+    oled.set_display_addr((0, 127), (0, 7))?;
+    oled.interface_mut().start(FRAME_SIZE)?;
+    Ok(())
+}
+
+fn serial_read<B: UsbBus>(serial: &mut SerialPort<B>, buffer: &mut [u8]) {
+    let mut offset = 0;
+    while offset < buffer.len() {
+        match serial.read(&mut buffer[offset..]) {
+            Ok(count) => {
+                offset += count;
+            }
+            Err(UsbError::WouldBlock) => {
+                continue;
+            }
+            Err(e) => {
+                panic!("read error: {:?}", e);
+            }
+        }
+    }
+}
+
+/// 解析`Signal::Scroll`的数据, 格式见`bw_img_comm::Signal::Scroll`
+#[cfg(not(feature = "sh1106"))]
+fn parse_scroll(data: &[u8]) -> Option<Scroll> {
+    let direction = match data[0] {
+        0 => ScrollDirection::Right,
+        1 => ScrollDirection::Left,
+        _ => return None,
+    };
+    let interval = match data[3] {
+        0 => FrameInterval::Frames2,
+        1 => FrameInterval::Frames3,
+        2 => FrameInterval::Frames4,
+        3 => FrameInterval::Frames5,
+        4 => FrameInterval::Frames25,
+        5 => FrameInterval::Frames64,
+        6 => FrameInterval::Frames128,
+        7 => FrameInterval::Frames256,
+        _ => return None,
+    };
+    Some(Scroll::diagonal(direction, (data[1], data[2]), interval, data[4]))
+}
+
 fn serial_write<B: UsbBus>(serial: &mut SerialPort<B>, signal: Signal) {
     let (first, data) = signal.to_bytes();
 
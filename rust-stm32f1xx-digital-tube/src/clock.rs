@@ -0,0 +1,187 @@
+//! 时钟界面的状态机, 与硬件无关
+//!
+//! RTC计数器是当天零点起的秒数, 只取一天内的部分显示HH:MM, 不记日期.
+//! MODE依次进入 调时 -> 调分 -> 闹钟时 -> 闹钟分 -> 返回时钟,
+//! UP在调整时加一, 在时钟界面开关闹钟. 闹钟响时按任意键停止.
+
+use digital_tube_rs::{Number, SegDisplay, TubeDisplay};
+
+use crate::key::Button;
+
+/// 一天的秒数
+pub const DAY: u32 = 24 * 60 * 60;
+
+/// 闹钟最多响这么多秒
+const RING_SECS: u32 = 60;
+
+/// 每天响一次的闹钟, 存在一个16位备份寄存器里
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alarm {
+    /// 零点起的分钟数
+    pub minutes: u16,
+    pub on: bool,
+}
+
+impl Alarm {
+    const ON: u16 = 1 << 15;
+
+    /// 寄存器内容无效(比如备份域刚复位)时返回`None`
+    pub fn from_backup(reg: u16) -> Option<Self> {
+        let minutes = reg & !Self::ON;
+        (minutes < 24 * 60).then_some(Alarm {
+            minutes,
+            on: reg & Self::ON != 0,
+        })
+    }
+
+    pub fn to_backup(self) -> u16 {
+        self.minutes | if self.on { Self::ON } else { 0 }
+    }
+
+    /// `now`之后(含)下一次响的RTC计数值
+    pub fn next(self, now: u32) -> u32 {
+        let at = now - now % DAY + self.minutes as u32 * 60;
+        if at >= now {
+            at
+        } else {
+            at + DAY
+        }
+    }
+}
+
+impl Default for Alarm {
+    /// 07:00, 关闭
+    fn default() -> Self {
+        Alarm {
+            minutes: 7 * 60,
+            on: false,
+        }
+    }
+}
+
+/// 按键要求主循环做的事
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// 把RTC计数器设为该值
+    SetTime(u32),
+    /// 保存闹钟并重新设置RTC闹钟
+    SetAlarm(Alarm),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Clock,
+    Hour,
+    Minute,
+    AlarmHour,
+    AlarmMinute,
+}
+
+pub struct Clock {
+    mode: Mode,
+    alarm: Alarm,
+    /// 正在调整的时间, 零点起的分钟数
+    edit: u16,
+    /// 闹钟响到这个时间为止
+    ringing: Option<u32>,
+}
+
+impl Clock {
+    pub fn new(alarm: Alarm) -> Self {
+        Clock {
+            mode: Mode::Clock,
+            alarm,
+            edit: 0,
+            ringing: None,
+        }
+    }
+
+    pub fn alarm(&self) -> Alarm {
+        self.alarm
+    }
+
+    /// RTC闹钟到时调用
+    pub fn ring(&mut self, now: u32) {
+        if self.alarm.on {
+            self.ringing = Some(now + RING_SECS);
+        }
+    }
+
+    pub fn is_ringing(&self, now: u32) -> bool {
+        self.ringing.is_some_and(|until| now < until)
+    }
+
+    /// 正在调时间或闹钟, 时钟界面的UP只开关闹钟, 不能连发
+    pub fn is_editing(&self) -> bool {
+        self.mode != Mode::Clock
+    }
+
+    /// 在别的界面按键时停止闹钟
+    pub fn silence(&mut self) {
+        self.ringing = None;
+    }
+
+    pub fn press(&mut self, button: Button, now: u32) -> Option<Action> {
+        if self.is_ringing(now) {
+            self.ringing = None;
+            return None;
+        }
+        match (self.mode, button) {
+            (Mode::Clock, Button::Mode) => {
+                self.edit = (now % DAY / 60) as u16;
+                self.mode = Mode::Hour;
+            }
+            (Mode::Clock, Button::Up) => {
+                self.alarm.on = !self.alarm.on;
+                return Some(Action::SetAlarm(self.alarm));
+            }
+            (Mode::Hour, Button::Mode) => self.mode = Mode::Minute,
+            (Mode::Minute, Button::Mode) => {
+                // 调好的时间从0秒开始走
+                let time = now - now % DAY + self.edit as u32 * 60;
+                self.edit = self.alarm.minutes;
+                self.mode = Mode::AlarmHour;
+                return Some(Action::SetTime(time));
+            }
+            (Mode::AlarmHour, Button::Mode) => self.mode = Mode::AlarmMinute,
+            (Mode::AlarmMinute, Button::Mode) => {
+                self.alarm.minutes = self.edit;
+                self.mode = Mode::Clock;
+                return Some(Action::SetAlarm(self.alarm));
+            }
+            (Mode::Hour | Mode::AlarmHour, Button::Up) => {
+                self.edit = (self.edit + 60) % (24 * 60);
+            }
+            (Mode::Minute | Mode::AlarmMinute, Button::Up) => {
+                self.edit = self.edit - self.edit % 60 + (self.edit % 60 + 1) % 60;
+            }
+            // 切换界面由主循环处理
+            (_, Button::App) => {}
+        }
+        None
+    }
+
+    /// 要显示的内容和闪烁的位
+    ///
+    /// 时钟界面第二位的小数点当冒号每秒闪一次, 闹钟开着时第四位小数点常亮.
+    /// 调整时闪烁正在调的两位, 调闹钟时只亮第四位小数点.
+    pub fn display(&self, now: u32) -> ([u8; 4], u8) {
+        let (minutes, dots, blink) = match self.mode {
+            Mode::Clock => {
+                let colon = if now.is_multiple_of(2) { 0b0010 } else { 0 };
+                let alarm = if self.alarm.on { 0b1000 } else { 0 };
+                let blink = if self.is_ringing(now) { 0b1111 } else { 0 };
+                ((now % DAY / 60) as u16, colon | alarm, blink)
+            }
+            Mode::Hour => (self.edit, 0b0010, 0b0011),
+            Mode::Minute => (self.edit, 0b0010, 0b1100),
+            Mode::AlarmHour => (self.edit, 0b1000, 0b0011),
+            Mode::AlarmMinute => (self.edit, 0b1000, 0b1100),
+        };
+        let number = Number {
+            n: minutes / 60 * 100 + minutes % 60,
+            dots,
+        };
+        (number.tube_bits().map(|s| s.bits()), blink)
+    }
+}
@@ -0,0 +1,121 @@
+//! 按键接地, 内部上拉: MODE -> PB12, UP -> PB13, APP -> PB14
+//!
+//! 两个边沿都触发EXTI15_10, 中断里消抖后记下按下, 主循环再取走.
+//! 时间都用厘秒计.
+
+use stm32f1xx_hal::afio;
+use stm32f1xx_hal::gpio::gpiob::{PB12, PB13, PB14};
+use stm32f1xx_hal::gpio::{Edge, ExtiPin, Input, PullUp};
+use stm32f1xx_hal::pac::EXTI;
+
+use crate::tick;
+
+/// 距上一个边沿不到这么久的按下当作抖动
+const DEBOUNCE: u32 = 3;
+/// 按住这么久后开始连发
+const REPEAT_DELAY: u32 = 50;
+/// 连发间隔
+const REPEAT_EVERY: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Button {
+    Mode,
+    Up,
+    /// 切换时钟/秒表/倒计时/传感器
+    App,
+}
+
+impl Button {
+    pub const ALL: [Button; 3] = [Button::Mode, Button::Up, Button::App];
+}
+
+pub struct Keys {
+    mode: PB12<Input<PullUp>>,
+    up: PB13<Input<PullUp>>,
+    app: PB14<Input<PullUp>>,
+    /// 每个键上一个边沿的时刻
+    edge: [u32; 3],
+    /// 下次连发的时刻, 只在按下的边沿设置, 松开后为`None`
+    repeat: [Option<u32>; 3],
+    /// 还没取走的按下, 第i位对应`Button::ALL[i]`
+    pressed: u8,
+}
+
+impl Keys {
+    pub fn new(
+        mut mode: PB12<Input<PullUp>>,
+        mut up: PB13<Input<PullUp>>,
+        mut app: PB14<Input<PullUp>>,
+        afio: &mut afio::Parts,
+        exti: &mut EXTI,
+    ) -> Self {
+        mode.make_interrupt_source(afio);
+        mode.trigger_on_edge(exti, Edge::RisingFalling);
+        mode.enable_interrupt(exti);
+        up.make_interrupt_source(afio);
+        up.trigger_on_edge(exti, Edge::RisingFalling);
+        up.enable_interrupt(exti);
+        app.make_interrupt_source(afio);
+        app.trigger_on_edge(exti, Edge::RisingFalling);
+        app.enable_interrupt(exti);
+        Keys {
+            mode,
+            up,
+            app,
+            edge: [0; 3],
+            // 上电时已经按住的键没有按下的边沿, 不连发
+            repeat: [None; 3],
+            pressed: 0,
+        }
+    }
+
+    /// 在EXTI15_10中断里调用
+    pub fn on_interrupt(&mut self, now: u32) {
+        // 有边沿时清除挂起位, 得到当前是否按下
+        macro_rules! edge {
+            ($pin:expr) => {
+                $pin.check_interrupt().then(|| {
+                    $pin.clear_interrupt_pending_bit();
+                    $pin.is_low()
+                })
+            };
+        }
+        let edges = [edge!(self.mode), edge!(self.up), edge!(self.app)];
+        for (i, edge) in edges.into_iter().enumerate() {
+            let Some(low) = edge else {
+                continue;
+            };
+            if !low {
+                self.repeat[i] = None;
+            } else {
+                if now.wrapping_sub(self.edge[i]) >= DEBOUNCE {
+                    self.pressed |= 1 << i;
+                }
+                // 按下时的抖动会先取消连发, 所以抖动的边沿也重新计时
+                self.repeat[i] = Some(now.wrapping_add(REPEAT_DELAY));
+            }
+            self.edge[i] = now;
+        }
+    }
+
+    /// 取走按下的键, `repeat`里的键按住时还会连发
+    pub fn take(&mut self, now: u32, repeat: &[Button]) -> u8 {
+        for button in repeat {
+            let i = *button as usize;
+            let due = self.repeat[i].is_some_and(|at| tick::reached(now, at));
+            if self.is_low(*button) && due && self.pressed & (1 << i) == 0 {
+                self.repeat[i] = Some(now.wrapping_add(REPEAT_EVERY));
+                self.pressed |= 1 << i;
+            }
+        }
+        core::mem::take(&mut self.pressed)
+    }
+
+    fn is_low(&self, button: Button) -> bool {
+        match button {
+            Button::Mode => self.mode.is_low(),
+            Button::Up => self.up.is_low(),
+            Button::App => self.app.is_low(),
+        }
+    }
+}
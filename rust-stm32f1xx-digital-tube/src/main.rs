@@ -1,47 +1,91 @@
 #![no_std]
 #![no_main]
 
+mod clock;
+mod key;
+mod segments;
+mod sensor;
+mod stopwatch;
+mod tick;
+
+use core::cell::RefCell;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use clock::{Action, Alarm, Clock};
+use cortex_m::interrupt::Mutex;
+use cortex_m::peripheral::NVIC;
 use defmt::println;
 use defmt_rtt as _;
-use display::Number;
+use digital_tube_rs::{Active, Marquee, Polarity, Tube, BRIGHTNESS_LEVELS};
 use fugit::RateExtU32;
+use key::{Button, Keys};
 use nb::block;
 use panic_probe as _;
+use segments::Segments;
+use sensor::{Scale, Sensor};
+use stopwatch::{Countdown, Stopwatch};
 
 use cortex_m_rt::entry;
-use stm32f1xx_hal::pac::{self, SYST};
-use stm32f1xx_hal::prelude::_stm32_hal_flash_FlashExt;
-use stm32f1xx_hal::rcc::{Clocks, RccExt};
-use stm32f1xx_hal::timer::{self, SysCounterHz};
-use tube::Tube;
-
-mod display;
-mod tube;
-
-pub struct Timer {
-    timer: SysCounterHz,
-    count: u64,
-}
+use stm32f1xx_hal::adc::Adc;
+use stm32f1xx_hal::afio::AfioExt;
+use stm32f1xx_hal::gpio::{ErasedPin, GpioExt, IOPinSpeed, Output, OutputSpeed, PinState};
+use stm32f1xx_hal::pac::{self, interrupt, TIM2, TIM3};
+use stm32f1xx_hal::prelude::{_embedded_hal_adc_OneShot, _stm32_hal_flash_FlashExt};
+use stm32f1xx_hal::rcc::RccExt;
+use stm32f1xx_hal::rtc::{RestoredOrNewRtc, Rtc};
+use stm32f1xx_hal::timer::{CounterHz, Event, SysTimerExt, TimerExt};
 
-impl Timer {
-    pub fn new(hz: u32, syst: SYST, clocks: &Clocks) -> Self {
-        let mut timer = timer::Timer::syst(syst, clocks).counter_hz();
-        timer.start(hz.Hz()).unwrap();
-        Self { timer, count: 0 }
-    }
+type Pin = ErasedPin<Output>;
 
-    pub fn delay(&mut self) {
-        block!(self.timer.wait()).unwrap();
-        self.count += 1;
-    }
+/// 中断里扫描的数码管和定时器, 主循环只设置显示内容
+static TUBE: Mutex<RefCell<Option<Tube<Segments, Pin>>>> = Mutex::new(RefCell::new(None));
+static SCAN_TIMER: Mutex<RefCell<Option<CounterHz<TIM2>>>> = Mutex::new(RefCell::new(None));
+/// 秒表和按键消抖用的厘秒计数, TIM3每10ms加一
+static CENTIS: AtomicU32 = AtomicU32::new(0);
+static TICK_TIMER: Mutex<RefCell<Option<CounterHz<TIM3>>>> = Mutex::new(RefCell::new(None));
+/// EXTI中断里记录按键
+static KEYS: Mutex<RefCell<Option<Keys>>> = Mutex::new(RefCell::new(None));
+
+/// 每位占BRIGHTNESS_LEVELS次中断, 4位轮流一次为90Hz
+const FREQ: u32 = 90 * 4 * BRIGHTNESS_LEVELS as u32;
+
+/// 主循环刷新显示和检查RTC的频率
+const POLL_FREQ: u32 = 50;
 
-    #[inline(always)]
-    pub fn count(&self) -> u64 {
-        self.count
+/// 倒计时默认3分钟
+const COUNTDOWN_PRESET: u32 = 3 * 60;
+
+/// 保存闹钟设置的备份寄存器
+const ALARM_REG: usize = 0;
+
+/// 数码管的点亮电平, 5461BS-1是共阳, 换共阴的管子或加了三极管只改这里
+const POLARITY: Polarity = Polarity::COMMON_ANODE;
+
+/// 熄灭时的电平
+const fn off(active: Active) -> PinState {
+    match active {
+        Active::Low => PinState::High,
+        Active::High => PinState::Low,
     }
 }
 
-const FREQ: u32 = 360;
+/// 段选引脚, 推挽输出10MHz, 初始熄灭
+macro_rules! seg_pin {
+    ($pin:expr, $cr:expr) => {{
+        let mut io = $pin.into_push_pull_output_with_state(&mut $cr, off(POLARITY.segments));
+        io.set_speed(&mut $cr, IOPinSpeed::Mhz10);
+        io.erase_number()
+    }};
+}
+
+/// 位选引脚, 推挽输出2MHz, 初始熄灭
+macro_rules! dig_pin {
+    ($pin:expr, $cr:expr) => {{
+        let mut io = $pin.into_push_pull_output_with_state(&mut $cr, off(POLARITY.digits));
+        io.set_speed(&mut $cr, IOPinSpeed::Mhz2);
+        io.erase()
+    }};
+}
 
 #[entry]
 fn main() -> ! {
@@ -52,24 +96,244 @@ fn main() -> ! {
     // 获得原始flash和rcc设备的所有权，并将它们转换为相应的HAL结构
     let mut flash = dp.FLASH.constrain();
     let rcc = dp.RCC.constrain();
+    let mut pwr = dp.PWR;
 
     // 冻结系统中所有时钟的配置，并将冻结的频率存储在时钟中
-    let clocks = rcc.cfgr.freeze(&mut flash.acr);
+    let clocks = rcc.cfgr.adcclk(2.MHz()).freeze(&mut flash.acr);
 
-    let mut timer = Timer::new(FREQ, cp.SYST, &clocks);
-    let mut tube = Tube::new(dp.GPIOA, dp.GPIOB);
+    let mut gpioa = dp.GPIOA.split();
+    let mut gpiob = dp.GPIOB.split();
+    let mut afio = dp.AFIO.constrain();
+    let mut exti = dp.EXTI;
+
+    // RTC用LSE(32.768kHz晶振)每秒计数一次, 与闹钟设置一起放在备份域,
+    // 复位后继续走, VBAT接电池时掉电也不丢
+    let mut backup = rcc.bkp.constrain(dp.BKP, &mut pwr);
+    let mut rtc = match Rtc::restore_or_new(dp.RTC, &mut backup) {
+        RestoredOrNewRtc::Restored(rtc) => rtc,
+        RestoredOrNewRtc::New(rtc) => {
+            println!("rtc reset to 00:00");
+            rtc
+        }
+    };
+    let mut clock = Clock::new(
+        Alarm::from_backup(backup.read_data_register_low(ALARM_REG)).unwrap_or_default(),
+    );
+    rtc.set_alarm(clock.alarm().next(rtc.current_time() + 1));
+
+    // USB的PA11/PA12已被段选占用, 所以只能用按键调时间
+    let keys = Keys::new(
+        gpiob.pb12.into_pull_up_input(&mut gpiob.crh),
+        gpiob.pb13.into_pull_up_input(&mut gpiob.crh),
+        gpiob.pb14.into_pull_up_input(&mut gpiob.crh),
+        &mut afio,
+        &mut exti,
+    );
+    // 传感器: 模拟量 -> PA1, 阈值比较器输出 -> PA0(低电平报警)
+    let mut analog = gpioa.pa1.into_analog(&mut gpioa.crl);
+    let threshold = gpioa.pa0.into_pull_up_input(&mut gpioa.crl);
+    let mut adc = Adc::adc1(dp.ADC1, clocks);
+
+    // 有源蜂鸣器 -> PB0, 高电平响
+    let mut buzzer = gpiob.pb0.into_push_pull_output(&mut gpiob.crl);
+
+    // 5461BS-1 4位, [文档](http://www.xlitx.com/datasheet/5461BS.pdf)
+    // 段选: A(11) -> PA7, B(7) -> PA6, C(4) -> PA11, D(2) -> PA9,
+    // E(1) -> PA8, F(10) -> PA5, G(5) -> PA12, DP(3) -> PA10
+    let segments = Segments::new([
+        seg_pin!(gpioa.pa7, gpioa.crl),
+        seg_pin!(gpioa.pa6, gpioa.crl),
+        seg_pin!(gpioa.pa11, gpioa.crh),
+        seg_pin!(gpioa.pa9, gpioa.crh),
+        seg_pin!(gpioa.pa8, gpioa.crh),
+        seg_pin!(gpioa.pa5, gpioa.crl),
+        seg_pin!(gpioa.pa12, gpioa.crh),
+        seg_pin!(gpioa.pa10, gpioa.crh),
+    ]);
+    // 位选: DIG1(12) -> PB11, DIG2(9) -> PB10, DIG3(8) -> PB1, DIG4(6) -> PB15
+    let digits = [
+        dig_pin!(gpiob.pb11, gpiob.crh),
+        dig_pin!(gpiob.pb10, gpiob.crh),
+        dig_pin!(gpiob.pb1, gpiob.crl),
+        dig_pin!(gpiob.pb15, gpiob.crh),
+    ];
+    println!("init digital tube...");
+    let tube = Tube::with_polarity(segments, digits, POLARITY).unwrap();
+
+    let mut scan_timer = dp.TIM2.counter_hz(&clocks);
+    scan_timer.start(FREQ.Hz()).unwrap();
+    scan_timer.listen(Event::Update);
+    let mut tick_timer = dp.TIM3.counter_hz(&clocks);
+    tick_timer.start(100.Hz()).unwrap();
+    tick_timer.listen(Event::Update);
+    cortex_m::interrupt::free(|cs| {
+        TUBE.borrow(cs).replace(Some(tube));
+        SCAN_TIMER.borrow(cs).replace(Some(scan_timer));
+        TICK_TIMER.borrow(cs).replace(Some(tick_timer));
+        KEYS.borrow(cs).replace(Some(keys));
+    });
+    // SAFETY: 中断用到的资源都已放入Mutex
+    unsafe {
+        NVIC::unmask(pac::Interrupt::TIM2);
+        NVIC::unmask(pac::Interrupt::TIM3);
+        NVIC::unmask(pac::Interrupt::EXTI15_10);
+    }
+
+    let mut poll = cp.SYST.counter_hz(&clocks);
+    poll.start(POLL_FREQ.Hz()).unwrap();
+
+    // 开机滚动显示一遍, 由扫描中断推动
+    let intro = Marquee::new("HELLO 5461").unwrap();
+    with_tube(|tube| tube.scroll(intro));
+    for _ in 0..5 * POLL_FREQ {
+        block!(poll.wait()).unwrap();
+    }
 
-    let mut secs = Number::new(0);
     println!("start loop");
-    tube.set_tube(secs.clone());
-    let mut dot = 0;
+    let mut app = App::Clock;
+    let mut stopwatch = Stopwatch::default();
+    let mut countdown = Countdown::new(COUNTDOWN_PRESET);
+    let mut sensor = Sensor::new(Scale::LM35);
+    let mut shown = None;
     loop {
-        if timer.count() % FREQ as u64 == 0 {
-            secs.n += 1;
-            dot += 1;
-            dot %= 4;
-            tube.set_tube(secs.clone().set_dot(dot));
+        block!(poll.wait()).unwrap();
+        let now = rtc.current_time();
+        let centis = CENTIS.load(Ordering::Relaxed);
+        if rtc.wait_alarm().is_ok() {
+            println!("alarm at {}", now);
+            clock.ring(now);
+            rtc.set_alarm(clock.alarm().next(now + 1));
+        }
+        countdown.tick(centis);
+        let raw: u16 = adc.read(&mut analog).unwrap();
+        sensor.sample(raw, threshold.is_low());
+
+        // 调时间时UP按住连发
+        let repeat: &[Button] = match app {
+            App::Clock if clock.is_editing() => &[Button::Up],
+            _ => &[],
+        };
+        let pressed = with_keys(|keys| keys.take(centis, repeat));
+        for (i, button) in Button::ALL.into_iter().enumerate() {
+            if pressed & (1 << i) == 0 {
+                continue;
+            }
+            // 在响的时候任意键只用来停止
+            if clock.is_ringing(now) {
+                clock.silence();
+                continue;
+            }
+            if countdown.is_ringing(centis) {
+                countdown.press(button, centis);
+                continue;
+            }
+            match (app, button) {
+                (_, Button::App) => app = app.next(),
+                (App::Clock, _) => match clock.press(button, now) {
+                    Some(Action::SetTime(time)) => {
+                        rtc.set_time(time);
+                        rtc.set_alarm(clock.alarm().next(time + 1));
+                    }
+                    Some(Action::SetAlarm(alarm)) => {
+                        backup.write_data_register_low(ALARM_REG, alarm.to_backup());
+                        rtc.set_alarm(alarm.next(now + 1));
+                    }
+                    None => {}
+                },
+                (App::Stopwatch, _) => stopwatch.press(button, centis),
+                (App::Countdown, _) => countdown.press(button, centis),
+                (App::Sensor, _) => sensor.press(button),
+            }
+        }
+
+        // 闹钟或倒计时响的时候每0.1秒通断一次
+        if (clock.is_ringing(now) || countdown.is_ringing(centis))
+            && (centis / 10).is_multiple_of(2)
+        {
+            buzzer.set_high();
+        } else {
+            buzzer.set_low();
+        }
+
+        // 只在内容变化时更新, 免得频繁关中断
+        let frame = match app {
+            App::Clock => clock.display(now),
+            App::Stopwatch => stopwatch.display(centis),
+            App::Countdown => countdown.display(centis),
+            App::Sensor => sensor.display(),
+        };
+        if shown != Some(frame) {
+            let (bits, blink) = frame;
+            with_tube(|tube| {
+                tube.set_tube(bits);
+                tube.set_blink(blink);
+            });
+            shown = Some(frame);
         }
-        tube.show(&mut timer);
     }
 }
+
+/// APP键依次切换的界面
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum App {
+    Clock,
+    Stopwatch,
+    Countdown,
+    Sensor,
+}
+
+impl App {
+    fn next(self) -> Self {
+        match self {
+            App::Clock => App::Stopwatch,
+            App::Stopwatch => App::Countdown,
+            App::Countdown => App::Sensor,
+            App::Sensor => App::Clock,
+        }
+    }
+}
+
+/// 在临界区内修改中断正在扫描的数码管
+fn with_tube(f: impl FnOnce(&mut Tube<Segments, Pin>)) {
+    cortex_m::interrupt::free(|cs| {
+        if let Some(tube) = TUBE.borrow(cs).borrow_mut().as_mut() {
+            f(tube);
+        }
+    });
+}
+
+fn with_keys<R>(f: impl FnOnce(&mut Keys) -> R) -> R {
+    cortex_m::interrupt::free(|cs| f(KEYS.borrow(cs).borrow_mut().as_mut().unwrap()))
+}
+
+#[interrupt]
+fn TIM2() {
+    cortex_m::interrupt::free(|cs| {
+        if let Some(timer) = SCAN_TIMER.borrow(cs).borrow_mut().as_mut() {
+            timer.clear_interrupt(Event::Update);
+        }
+        if let Some(tube) = TUBE.borrow(cs).borrow_mut().as_mut() {
+            tube.refresh().unwrap();
+        }
+    });
+}
+
+#[interrupt]
+fn TIM3() {
+    cortex_m::interrupt::free(|cs| {
+        if let Some(timer) = TICK_TIMER.borrow(cs).borrow_mut().as_mut() {
+            timer.clear_interrupt(Event::Update);
+        }
+    });
+    CENTIS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[interrupt]
+fn EXTI15_10() {
+    let now = CENTIS.load(Ordering::Relaxed);
+    cortex_m::interrupt::free(|cs| {
+        if let Some(keys) = KEYS.borrow(cs).borrow_mut().as_mut() {
+            keys.on_interrupt(now);
+        }
+    });
+}
@@ -0,0 +1,42 @@
+//! 段选都在GPIOA上, 一次写BSRR同时更新8根线
+
+use core::convert::Infallible;
+
+use digital_tube_rs::SegmentPort;
+use stm32f1xx_hal::gpio::gpioa::PA;
+use stm32f1xx_hal::gpio::{Output, PinExt};
+use stm32f1xx_hal::pac::GPIOA;
+
+/// 持有已配置为推挽输出的段选引脚, 保证写的这几位不会被别处当作其他用途
+pub struct Segments {
+    _pins: [PA<Output>; 8],
+    /// 各段在BSRR低16位的置位掩码, 左移16位就是复位掩码
+    masks: [u32; 8],
+}
+
+impl Segments {
+    /// 按A, B, C, D, E, F, G, DP的顺序, 具体接哪个引脚由调用方决定
+    pub fn new(pins: [PA<Output>; 8]) -> Self {
+        let masks = pins.each_ref().map(|pin| 1 << pin.pin_id());
+        Segments { _pins: pins, masks }
+    }
+}
+
+impl SegmentPort for Segments {
+    type Error = Infallible;
+
+    fn write_levels(&mut self, levels: u8) -> Result<(), Self::Error> {
+        let mut bits = 0;
+        for (i, &mask) in self.masks.iter().enumerate() {
+            bits |= if (levels >> i) & 1 == 1 {
+                mask
+            } else {
+                mask << 16
+            };
+        }
+        // BSRR只影响写1的位, 不用读-改-写, 中断里别的GPIOA操作也不会被覆盖
+        // SAFETY: 写1的位都属于本结构持有的引脚
+        unsafe { (*GPIOA::ptr()).bsrr.write(|w| w.bits(bits)) };
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+//! 传感器读数界面, 与硬件无关
+//!
+//! 模拟量接PA1, 阈值比较器的数字输出接PA0, 跟rust-stm32f1xx-sensor一样.
+//! MODE依次切换 原始值 -> 毫伏 -> 换算后的工程单位, PA0为低时整体闪烁报警.
+
+use digital_tube_rs::{Fixed, SegDisplay, Segment, Signed, TubeDisplay};
+
+use crate::key::Button;
+
+/// 参考电压, 毫伏
+const VDDA: u32 = 3300;
+/// 12位ADC满量程
+const FULL_SCALE: u32 = 4095;
+/// 取这么多次采样的平均值显示一次, 主循环50Hz时每0.5秒更新
+const SAMPLES: u32 = 25;
+
+/// 毫伏到工程单位的线性换算: `(mv * mul / div + offset) / 10^decimals`
+#[derive(Debug, Clone, Copy)]
+pub struct Scale {
+    pub mul: i32,
+    pub div: i32,
+    pub offset: i32,
+    pub decimals: u8,
+}
+
+impl Scale {
+    /// LM35温度传感器, 10mV/°C, 即每毫伏0.1°C
+    pub const LM35: Scale = Scale {
+        mul: 1,
+        div: 1,
+        offset: 0,
+        decimals: 1,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Raw,
+    Millivolts,
+    Scaled,
+}
+
+pub struct Sensor {
+    scale: Scale,
+    unit: Unit,
+    sum: u32,
+    count: u32,
+    /// 最近一次的平均值, 还没采够时为`None`
+    raw: Option<u16>,
+    alarm: bool,
+}
+
+impl Sensor {
+    pub fn new(scale: Scale) -> Self {
+        Sensor {
+            scale,
+            unit: Unit::Raw,
+            sum: 0,
+            count: 0,
+            raw: None,
+            alarm: false,
+        }
+    }
+
+    /// 每次主循环调用, `low`是PA0是否为低
+    pub fn sample(&mut self, raw: u16, low: bool) {
+        self.alarm = low;
+        self.sum += raw as u32;
+        self.count += 1;
+        if self.count == SAMPLES {
+            self.raw = Some((self.sum / SAMPLES) as u16);
+            self.sum = 0;
+            self.count = 0;
+        }
+    }
+
+    pub fn press(&mut self, button: Button) {
+        if button == Button::Mode {
+            self.unit = match self.unit {
+                Unit::Raw => Unit::Millivolts,
+                Unit::Millivolts => Unit::Scaled,
+                Unit::Scaled => Unit::Raw,
+            };
+        }
+    }
+
+    /// 还没有读数时显示横线
+    pub fn display(&self) -> ([u8; 4], u8) {
+        let blink = if self.alarm { 0b1111 } else { 0 };
+        let Some(raw) = self.raw else {
+            return ([Segment::G; 4], blink);
+        };
+        let mv = (raw as u32 * VDDA / FULL_SCALE) as i32;
+        let bits = match self.unit {
+            Unit::Raw => Signed::new(raw as i32).tube_bits().map(|s| s.bits()),
+            Unit::Millivolts => Signed::new(mv).tube_bits().map(|s| s.bits()),
+            Unit::Scaled => {
+                let Scale {
+                    mul,
+                    div,
+                    offset,
+                    decimals,
+                } = self.scale;
+                Fixed::new(mv * mul / div + offset, decimals)
+                    .tube_bits()
+                    .map(|s| s.bits())
+            }
+        };
+        (bits, blink)
+    }
+}
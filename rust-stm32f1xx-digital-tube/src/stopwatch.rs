@@ -0,0 +1,182 @@
+//! 秒表和倒计时, 时间都用厘秒计, 与硬件无关
+//!
+//! 秒表: UP开始/暂停, 走时MODE记圈并显示2秒; 暂停时MODE依次查看记下的圈,
+//! 看完再按清零. 倒计时: UP开始/暂停, 停止时MODE先回到设定值, 再按每次加1分钟,
+//! 到0蜂鸣, 按任意键停止并回到设定值.
+
+use digital_tube_rs::{Number, SegDisplay, TubeDisplay, OVERFLOW};
+
+use crate::key::Button;
+use crate::tick;
+
+/// 最多记住的圈数, 满了丢掉最早的
+const LAPS: usize = 8;
+/// 记圈后显示圈时的厘秒数
+const LAP_SHOW: u32 = 200;
+/// 倒计时到0后最多响这么多厘秒
+const RING: u32 = 10 * 100;
+/// 倒计时最长99分钟
+const MAX_PRESET: u32 = 99 * 60;
+
+#[derive(Default)]
+pub struct Stopwatch {
+    /// 开始走时的时刻, 暂停时为`None`
+    started: Option<u32>,
+    /// 之前走过的时间
+    elapsed: u32,
+    laps: [u32; LAPS],
+    lap_count: usize,
+    /// 最近记的一圈显示到这个时刻
+    lap_until: Option<u32>,
+    /// 暂停时正在查看的圈
+    recall: Option<usize>,
+}
+
+impl Stopwatch {
+    fn total(&self, now: u32) -> u32 {
+        self.elapsed + self.started.map_or(0, |t| now.wrapping_sub(t))
+    }
+
+    /// 走时中刚记了一圈, 还在显示它
+    fn showing_lap(&self, now: u32) -> bool {
+        self.started.is_some()
+            && self
+                .lap_until
+                .is_some_and(|until| !tick::reached(now, until))
+    }
+
+    pub fn press(&mut self, button: Button, now: u32) {
+        match (button, self.started) {
+            (Button::Up, Some(t)) => {
+                self.elapsed += now.wrapping_sub(t);
+                self.started = None;
+            }
+            (Button::Up, None) => {
+                self.recall = None;
+                self.started = Some(now);
+            }
+            (Button::Mode, Some(_)) => {
+                if self.lap_count == LAPS {
+                    self.laps.copy_within(1.., 0);
+                    self.lap_count -= 1;
+                }
+                self.laps[self.lap_count] = self.total(now);
+                self.lap_count += 1;
+                self.lap_until = Some(now.wrapping_add(LAP_SHOW));
+            }
+            (Button::Mode, None) => {
+                let next = self.recall.map_or(0, |i| i + 1);
+                if next < self.lap_count {
+                    self.recall = Some(next);
+                } else {
+                    *self = Stopwatch::default();
+                }
+            }
+            (Button::App, _) => {}
+        }
+    }
+
+    /// 查看圈时全部闪烁
+    pub fn display(&self, now: u32) -> ([u8; 4], u8) {
+        match self.recall {
+            Some(i) => (stopwatch_bits(self.laps[i]), 0b1111),
+            None if self.showing_lap(now) => (stopwatch_bits(self.laps[self.lap_count - 1]), 0),
+            None => (stopwatch_bits(self.total(now)), 0),
+        }
+    }
+}
+
+pub struct Countdown {
+    /// 设定值, 秒
+    preset: u32,
+    /// 暂停时剩下的厘秒
+    remaining: u32,
+    /// 走时的话到0的时刻
+    end: Option<u32>,
+    /// 响到这个时刻为止
+    ringing: Option<u32>,
+}
+
+impl Countdown {
+    pub fn new(preset: u32) -> Self {
+        Countdown {
+            preset,
+            remaining: preset * 100,
+            end: None,
+            ringing: None,
+        }
+    }
+
+    fn left(&self, now: u32) -> u32 {
+        self.end.map_or(self.remaining, |end| tick::until(now, end))
+    }
+
+    /// 每次主循环调用, 到0时开始响
+    pub fn tick(&mut self, now: u32) {
+        if self.end.is_some_and(|end| tick::reached(now, end)) {
+            self.end = None;
+            self.remaining = 0;
+            self.ringing = Some(now.wrapping_add(RING));
+        }
+    }
+
+    pub fn is_ringing(&self, now: u32) -> bool {
+        self.ringing.is_some_and(|until| !tick::reached(now, until))
+    }
+
+    pub fn press(&mut self, button: Button, now: u32) {
+        if self.ringing.take().is_some() || self.remaining == 0 {
+            *self = Countdown::new(self.preset);
+            return;
+        }
+        match (button, self.end) {
+            (Button::Up, Some(end)) => {
+                self.remaining = tick::until(now, end);
+                self.end = None;
+            }
+            (Button::Up, None) => self.end = Some(now.wrapping_add(self.remaining)),
+            (Button::Mode, None) => {
+                if self.remaining != self.preset * 100 {
+                    self.remaining = self.preset * 100;
+                } else {
+                    *self = Countdown::new(self.preset % MAX_PRESET + 60);
+                }
+            }
+            (Button::Mode, Some(_)) | (Button::App, _) => {}
+        }
+    }
+
+    /// 显示MM.SS, 不足一秒按一秒算, 响的时候闪烁
+    pub fn display(&self, now: u32) -> ([u8; 4], u8) {
+        let secs = self.left(now).div_ceil(100);
+        let blink = if self.is_ringing(now) { 0b1111 } else { 0 };
+        (minutes_seconds(secs), blink)
+    }
+}
+
+/// 不到100秒显示SS.cc, 否则MM.SS, 超过99:59显示溢出
+fn stopwatch_bits(centis: u32) -> [u8; 4] {
+    if centis < 100 * 100 {
+        number(centis)
+    } else {
+        minutes_seconds(centis / 100)
+    }
+}
+
+fn minutes_seconds(secs: u32) -> [u8; 4] {
+    if secs < 100 * 60 {
+        number(secs / 60 * 100 + secs % 60)
+    } else {
+        OVERFLOW
+    }
+}
+
+/// 4位数字, 第二位带小数点
+fn number(n: u32) -> [u8; 4] {
+    Number {
+        n: n as u16,
+        dots: 0b0010,
+    }
+    .tube_bits()
+    .map(|s| s.bits())
+}
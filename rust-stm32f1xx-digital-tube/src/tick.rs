@@ -0,0 +1,16 @@
+//! `CENTIS`是回绕的u32厘秒计数, 约497天绕回0一次.
+//! 时刻只用回绕加减, 相差不到约248天时比较都对.
+
+/// `now`是否已到`deadline`
+pub fn reached(now: u32, deadline: u32) -> bool {
+    now.wrapping_sub(deadline) as i32 >= 0
+}
+
+/// 到`deadline`还剩的厘秒, 已经到了为0
+pub fn until(now: u32, deadline: u32) -> u32 {
+    if reached(now, deadline) {
+        0
+    } else {
+        deadline.wrapping_sub(now)
+    }
+}
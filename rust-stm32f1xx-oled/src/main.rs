@@ -4,11 +4,13 @@
 
 use defmt_rtt as _;
 use fugit::RateExtU32;
-use iic_oled_rs::Oled;
+use iic_oled_rs::{Error, Oled};
+use iic_oled_stm32f1::DmaI2cInterface;
 use panic_probe as _;
 
 use cortex_m_rt::entry;
 use stm32f1xx_hal::afio::AfioExt;
+use stm32f1xx_hal::dma::DmaExt;
 use stm32f1xx_hal::gpio::GpioExt;
 use stm32f1xx_hal::i2c::{BlockingI2c, DutyCycle, Mode};
 use stm32f1xx_hal::pac;
@@ -52,9 +54,15 @@ fn main() -> ! {
         1000,
         1000,
     );
-    let mut oled = Oled::<64, _>::new(i2c);
-    oled.init().unwrap();
-    oled.clear().unwrap();
+    // 显存数据走DMA1通道6(I2C1_TX)后台发送
+    let dma1 = dp.DMA1.split();
+    let buffer = cortex_m::singleton!(: [u8; 128 * 8] = [0; 128 * 8]).unwrap();
+    let mut oled = Oled::<64, _>::new(DmaI2cInterface::new(i2c, dma1.6, buffer));
+    match oled.probe().and_then(|_| oled.init()).and_then(|_| oled.clear()) {
+        Ok(()) => {}
+        Err(Error::NotFound) => panic!("oled not found at 0x3C/0x3D"),
+        Err(e) => panic!("oled init failed: {:?}", e),
+    }
 
     let mut count = 0;
     loop {
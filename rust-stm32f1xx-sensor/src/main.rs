@@ -2,24 +2,33 @@
 #![no_main]
 #![deny(unsafe_code)]
 
+#[allow(unsafe_code)]
+mod sampler;
+
+use cortex_m::singleton;
 use defmt::println;
 use defmt_rtt as _;
 use fugit::RateExtU32;
-use nb::block;
 use panic_probe as _;
 
 use cortex_m_rt::entry;
+use sampler::{AdcPins, CHANNELS};
 use stm32f1xx_hal::adc::Adc;
+use stm32f1xx_hal::dma::{DmaExt, Half};
 use stm32f1xx_hal::gpio::GpioExt;
 use stm32f1xx_hal::pac;
 use stm32f1xx_hal::prelude::*;
 use stm32f1xx_hal::rcc::RccExt;
-use stm32f1xx_hal::timer::Timer;
+
+/// 每秒扫描的次数, 由TIM3触发
+const SAMPLE_RATE: u32 = 256;
+/// 每半个缓冲区的扫描次数, 写满一半平均一次, 即每秒输出SAMPLE_RATE / SCANS次
+const SCANS: usize = 128;
+const HALF_LEN: usize = SCANS * CHANNELS;
 
 #[entry]
 fn main() -> ! {
     // 获取对外设的访问对象
-    let cp = cortex_m::Peripherals::take().unwrap();
     let dp = pac::Peripherals::take().unwrap();
 
     // 获得原始flash和rcc设备的所有权，并将它们转换为相应的HAL结构
@@ -28,17 +37,39 @@ fn main() -> ! {
     let mut gpioa = dp.GPIOA.split();
     let io0 = gpioa.pa0.into_pull_up_input(&mut gpioa.crl);
 
-    let mut io1 = gpioa.pa1.into_analog(&mut gpioa.crl);
+    let io1 = gpioa.pa1.into_analog(&mut gpioa.crl);
     let clocks = rcc.cfgr.adcclk(2u32.MHz()).freeze(&mut flash.acr);
-    let mut adc = Adc::adc1(dp.ADC1, clocks);
-    let mut timer = Timer::syst(cp.SYST, &clocks).counter_hz();
-    timer.start(1.Hz()).unwrap();
+    let dma_ch1 = dp.DMA1.split().1;
+    let adc = Adc::adc1(dp.ADC1, clocks);
+
+    let mut trigger = dp.TIM3.counter_hz(&clocks);
+    sampler::trigger_on_update(&trigger);
+    trigger.start(SAMPLE_RATE.Hz()).unwrap();
+
+    // 两半轮流写入, 读一半时DMA在写另一半
+    let buffer = singleton!(: [[u16; HALF_LEN]; 2] = [[0; HALF_LEN]; 2]).unwrap();
+    let mut circ = adc.with_scan_dma(AdcPins(io1), dma_ch1).circ_read(buffer);
+    let mut next = Half::First;
     loop {
-        let sig: u16 = adc.read(&mut io1).unwrap();
+        while circ.readable_half().unwrap() != next {}
+        let reading = circ.peek(|half, _| sampler::average(half)).unwrap();
+        next = match next {
+            Half::First => Half::Second,
+            Half::Second => Half::First,
+        };
+
         if io0.is_low() {
             println!("sensor low");
         }
-        println!("input: {}", sig);
-        block!(timer.wait()).unwrap();
+        let temp = reading.temperature;
+        println!(
+            "input: {} ({} mV), vdda: {} mV, temp: {}{}.{} C",
+            reading.raw,
+            reading.millivolts,
+            reading.vdda,
+            if temp < 0 { "-" } else { "" },
+            temp.abs() / 10,
+            temp.abs() % 10
+        );
     }
 }
@@ -0,0 +1,91 @@
+//! ADC1扫描PA1, 内部温度传感器和VREFINT, 由TIM3的更新事件(TRGO)触发,
+//! DMA1通道1循环写入缓冲区, 每写满一半取平均得到一次读数
+
+use stm32f1xx_hal::adc::{Adc, SampleTime, SetChannels};
+use stm32f1xx_hal::gpio::{gpioa::PA1, Analog};
+use stm32f1xx_hal::pac::{self, ADC1, TIM3};
+use stm32f1xx_hal::timer::CounterHz;
+
+/// 每次扫描的通道: PA1, 温度传感器, VREFINT
+pub const CHANNELS: usize = 3;
+const SEQUENCE: [u8; CHANNELS] = [1, 16, 17];
+
+/// 内部参考电压典型值, 毫伏
+const VREFINT: u32 = 1200;
+/// 温度传感器25°C时的电压(毫伏)和斜率(0.1毫伏/°C), 见数据手册典型值
+const V25: i32 = 1430;
+const AVG_SLOPE: i32 = 43;
+
+pub struct AdcPins(pub PA1<Analog>);
+
+impl SetChannels<AdcPins> for Adc<ADC1> {
+    fn set_samples(&mut self) {
+        self.set_channel_sample_time(1, SampleTime::T_55);
+        // 温度传感器要求采样时间不少于17.1us, 2MHz ADC时钟下取最长的
+        self.set_channel_sample_time(16, SampleTime::T_239);
+        self.set_channel_sample_time(17, SampleTime::T_239);
+    }
+
+    fn set_sequence(&mut self) {
+        self.set_regular_sequence(&SEQUENCE);
+        self.set_continuous_mode(false);
+        // 打开温度传感器和VREFINT, 改为TIM3 TRGO触发一次扫描
+        adc1().cr2.modify(|_, w| {
+            w.tsvrefe()
+                .set_bit()
+                .exttrig()
+                .set_bit()
+                .extsel()
+                .tim3trgo()
+        });
+    }
+}
+
+/// 让TIM3在每次更新事件时输出TRGO, 定时器频率就是扫描频率
+pub fn trigger_on_update(_timer: &CounterHz<TIM3>) {
+    tim3().cr2.modify(|_, w| w.mms().update());
+}
+
+/// 一次平均后的读数
+#[derive(Debug, Clone, Copy, defmt::Format)]
+pub struct Reading {
+    /// PA1的原始值
+    pub raw: u16,
+    /// 由VREFINT算出的实际供电电压, 毫伏
+    pub vdda: u32,
+    /// PA1的电压, 毫伏
+    pub millivolts: u32,
+    /// 芯片温度, 0.1°C
+    pub temperature: i32,
+}
+
+/// 对半个缓冲区里的所有扫描求平均
+pub fn average(samples: &[u16]) -> Reading {
+    let mut sums = [0u32; CHANNELS];
+    let mut scans = 0;
+    for scan in samples.chunks_exact(CHANNELS) {
+        sums.iter_mut()
+            .zip(scan)
+            .for_each(|(sum, s)| *sum += *s as u32);
+        scans += 1;
+    }
+    let [input, temp, vref] = sums.map(|sum| sum / scans.max(1));
+    let vdda = VREFINT * 4095 / vref.max(1);
+    let to_mv = |raw: u32| raw * vdda / 4095;
+    Reading {
+        raw: input as u16,
+        vdda,
+        millivolts: to_mv(input),
+        temperature: (V25 - to_mv(temp) as i32) * 100 / AVG_SLOPE + 250,
+    }
+}
+
+fn adc1() -> &'static pac::adc1::RegisterBlock {
+    // SAFETY: Adc<ADC1>持有ADC1, 这里只在它配置通道时调用
+    unsafe { &*ADC1::ptr() }
+}
+
+fn tim3() -> &'static pac::tim3::RegisterBlock {
+    // SAFETY: 只改CR2的MMS, 计数器本身仍由HAL的定时器管理
+    unsafe { &*TIM3::ptr() }
+}